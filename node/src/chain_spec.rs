@@ -1,6 +1,7 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature,
-	SubsPlayGroundConfig, SudoConfig, SystemConfig, WASM_BINARY,
+	pallet_kittens::Gender, AccountId, AuraConfig, Balance, BalancesConfig, GenesisConfig,
+	GrandpaConfig, Signature, SubsPlayGroundConfig, SubstrateKittiesConfig, SudoConfig,
+	SystemConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -59,6 +60,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Pre-minted kitties
+				genesis_kitties(&[
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				]),
 				true,
 			)
 		},
@@ -107,6 +113,13 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Pre-minted kitties
+				genesis_kitties(&[
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+					get_account_id_from_seed::<sr25519::Public>("Dave"),
+				]),
 				true,
 			)
 		},
@@ -130,6 +143,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	kitties: Vec<(AccountId, [u8; 16], Gender, Option<Balance>)>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -153,5 +167,23 @@ fn testnet_genesis(
 		},
 		transaction_payment: Default::default(),
 		subs_play_ground: SubsPlayGroundConfig { myfield: 0 },
+		substrate_kitties: SubstrateKittiesConfig { kitties },
 	}
 }
+
+/// A male and a female kitty for every owner, the female one listed for sale.
+///
+/// The lowest bit of the dna decides the gender of a kitty, so even seeds are male.
+fn genesis_kitties(owners: &[AccountId]) -> Vec<(AccountId, [u8; 16], Gender, Option<Balance>)> {
+	owners
+		.iter()
+		.enumerate()
+		.flat_map(|(i, owner)| {
+			let seed = 2 * i as u8;
+			vec![
+				(owner.clone(), [seed; 16], Gender::Male, None),
+				(owner.clone(), [seed + 1; 16], Gender::Female, Some(1 << 50)),
+			]
+		})
+		.collect()
+}
@@ -0,0 +1,174 @@
+//! RPC interface for the kittens pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use pallet_kittens::genome;
+pub use pallet_kittens_runtime_api::{
+	FurColour, KittiesApi as KittiesRuntimeApi, KittyInfo, MarketInfo, SaleInfo, Traits,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
+
+/// Largest page `kitties_forSale` returns.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Kitty queries, answered from the state at block `at` or the best block.
+#[rpc]
+pub trait KittiesApi<BlockHash, BlockNumber, AccountId, Balance> {
+	/// Ids of the kitties held by `owner`.
+	#[rpc(name = "kitties_kittiesOf")]
+	fn kitties_of(&self, owner: AccountId, at: Option<BlockHash>) -> Result<Vec<[u8; 16]>>;
+
+	/// Details of a single kitty.
+	#[rpc(name = "kitties_kitty")]
+	fn kitty(
+		&self,
+		kitty_id: [u8; 16],
+		at: Option<BlockHash>,
+	) -> Result<Option<KittyInfo<AccountId, Balance, BlockNumber>>>;
+
+	/// A page of at most `limit` kitties for sale with their price, starting after `start`.
+	#[rpc(name = "kitties_forSale")]
+	fn kitties_for_sale(
+		&self,
+		start: Option<[u8; 16]>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<([u8; 16], Balance)>>;
+
+	/// Number of kitties in existence.
+	#[rpc(name = "kitties_count")]
+	fn kitty_count(&self, at: Option<BlockHash>) -> Result<u64>;
+
+	/// Latest sales of a kitty, oldest first.
+	#[rpc(name = "kitties_saleHistory")]
+	fn sale_history(
+		&self,
+		kitty_id: [u8; 16],
+		at: Option<BlockHash>,
+	) -> Result<Vec<SaleInfo<Balance, BlockNumber>>>;
+
+	/// Sales statistics of the kitties with fur of the colour `fur`.
+	#[rpc(name = "kitties_marketStats")]
+	fn market_stats(
+		&self,
+		fur: FurColour,
+		at: Option<BlockHash>,
+	) -> Result<Option<MarketInfo<Balance, BlockNumber>>>;
+
+	/// Traits encoded in any dna, decoded by the node with the same decoder as the runtime.
+	#[rpc(name = "kitties_decodeDna")]
+	fn decode_dna(&self, dna: [u8; 16]) -> Result<Traits>;
+}
+
+/// Implements [`KittiesApi`] on top of the runtime API of a client.
+pub struct Kitties<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Kitties<C, Block> {
+	/// Create a new instance querying `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code of a failed runtime call.
+const RUNTIME_ERROR: i64 = 1;
+
+// report a failed runtime call to the client
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Runtime error".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+impl<C, Block, AccountId, Balance>
+	KittiesApi<<Block as BlockT>::Hash, NumberFor<Block>, AccountId, Balance> for Kitties<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: KittiesRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec,
+{
+	fn kitties_of(
+		&self,
+		owner: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<[u8; 16]>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.kitties_of(&at, owner)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn kitty(
+		&self,
+		kitty_id: [u8; 16],
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<KittyInfo<AccountId, Balance, NumberFor<Block>>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.kitty(&at, kitty_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn kitties_for_sale(
+		&self,
+		start: Option<[u8; 16]>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<([u8; 16], Balance)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.kitties_for_sale(&at, start, limit.min(MAX_PAGE_SIZE))
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn kitty_count(&self, at: Option<<Block as BlockT>::Hash>) -> Result<u64> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client.runtime_api().kitty_count(&at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn sale_history(
+		&self,
+		kitty_id: [u8; 16],
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<SaleInfo<Balance, NumberFor<Block>>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.sale_history(&at, kitty_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn market_stats(
+		&self,
+		fur: FurColour,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<MarketInfo<Balance, NumberFor<Block>>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.market_stats(&at, fur)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn decode_dna(&self, dna: [u8; 16]) -> Result<Traits> {
+		Ok(genome::decode(&dna))
+	}
+}
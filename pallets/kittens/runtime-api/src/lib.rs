@@ -0,0 +1,96 @@
+//! Runtime API definition for the kittens pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+pub use pallet_kittens::{
+	genome::{FurColour, Traits},
+	Gender, Lock,
+};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{traits::NumberFor, Permill};
+use sp_std::vec::Vec;
+
+/// A kitty as seen by clients.
+#[derive(Clone, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct KittyInfo<AccountId, Balance, BlockNumber> {
+	pub dna: [u8; 16],
+	pub gender: Gender,
+	/// Traits decoded from the dna.
+	pub traits: Traits,
+	pub owner: AccountId,
+	pub creator: AccountId,
+	/// Price the kitty can be bought for in the current block, from a fixed price listing or
+	/// a dutch auction.
+	pub price: Option<Balance>,
+	pub royalty: Permill,
+	pub parents: Option<([u8; 16], [u8; 16])>,
+	pub generation: u32,
+	/// Lock keeping the kitty with its owner, if one is in force.
+	pub lock: Option<Lock<BlockNumber>>,
+}
+
+/// A past sale of a kitty.
+#[derive(Clone, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct SaleInfo<Balance, BlockNumber> {
+	pub price: Balance,
+	pub block: BlockNumber,
+}
+
+/// Sales of the kitties of one fur colour.
+#[derive(Clone, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MarketInfo<Balance, BlockNumber> {
+	/// Total price of the sales over the last stats period of the pallet.
+	pub volume: Balance,
+	/// Number of sales ever made.
+	pub sales: u32,
+	pub last_price: Balance,
+	pub last_sale: BlockNumber,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Read access to the kitty marketplace.
+	pub trait KittiesApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Price a kitty in a dutch auction can be bought for in the current block.
+		fn dutch_auction_price(kitty_id: [u8; 16]) -> Option<Balance>;
+
+		/// Marketplace fee the seller pays if the kitty is bought in the current block.
+		fn sale_fee(kitty_id: [u8; 16]) -> Option<Balance>;
+
+		/// Account approved to transfer a single kitty.
+		fn approved(kitty_id: [u8; 16]) -> Option<AccountId>;
+
+		/// Whether `operator` may transfer every kitty of `owner`.
+		fn is_approved_for_all(owner: AccountId, operator: AccountId) -> bool;
+
+		/// Ids of the kitties held by `owner`.
+		fn kitties_of(owner: AccountId) -> Vec<[u8; 16]>;
+
+		/// Details of a single kitty.
+		fn kitty(kitty_id: [u8; 16]) -> Option<KittyInfo<AccountId, Balance, NumberFor<Block>>>;
+
+		/// Up to `limit` kitties that can be bought in the current block with their price.
+		///
+		/// Pages follow the storage order of the listed kitties, pass the last id of a page as
+		/// `start` to get the next one.
+		fn kitties_for_sale(start: Option<[u8; 16]>, limit: u32) -> Vec<([u8; 16], Balance)>;
+
+		/// Number of kitties in existence.
+		fn kitty_count() -> u64;
+
+		/// Latest sales of a kitty, oldest first.
+		fn sale_history(kitty_id: [u8; 16]) -> Vec<SaleInfo<Balance, NumberFor<Block>>>;
+
+		/// Sales statistics of the kitties with fur of the colour `fur`, if any was ever sold.
+		fn market_stats(fur: FurColour) -> Option<MarketInfo<Balance, NumberFor<Block>>>;
+	}
+}
@@ -1,20 +1,616 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-kittens
 
 use super::*;
 
-#[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::Pallet as Kittens;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	dispatch::DispatchError,
+	sp_runtime::traits::Bounded,
+	sp_std::{vec, vec::Vec},
+	traits::{Currency, Get, Hooks},
+};
 use frame_system::RawOrigin;
 
+const SEED: u32 = 0;
+
+// an account with enough funds for any price used below
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	fund::<T>(&who);
+	who
+}
+
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 4u32.into());
+}
+
+// a price comfortably above the existential deposit
+fn price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance() * 100u32.into()
+}
+
+// a dna with `seed` in it, odd seeds are female
+fn dna(seed: u32) -> [u8; 16] {
+	let mut dna = [0u8; 16];
+	dna[..4].copy_from_slice(&seed.to_le_bytes());
+	dna
+}
+
+// mint a kitty for `owner` with `seed` in its dna
+fn mint_kitty<T: Config>(owner: &T::AccountId, seed: u32) -> Result<[u8; 16], DispatchError> {
+	let dna = dna(seed);
+	Kittens::<T>::mint(owner, dna, Kittens::<T>::gender_of(&dna))
+}
+
+// fill the owned index of `owner` with `n` kitties minted from seeds after `first`
+fn mint_kitties<T: Config>(owner: &T::AccountId, first: u32, n: u32) -> Result<(), DispatchError> {
+	for seed in first..first + n {
+		mint_kitty::<T>(owner, seed)?;
+	}
+	Ok(())
+}
+
+// open `o` offers on a kitty from distinct bidders
+fn make_offers<T: Config>(kitty_id: [u8; 16], o: u32) -> Result<(), DispatchError> {
+	let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	for i in 0..o {
+		let bidder = funded_account::<T>("bidder", i);
+		Kittens::<T>::make_offer(RawOrigin::Signed(bidder).into(), kitty_id, price::<T>(), expiry)?;
+	}
+	Ok(())
+}
+
+// a kitty owned by `owner` that pays its creator the highest royalty on every sale
+fn royalty_kitty<T: Config>(owner: &T::AccountId) -> Result<[u8; 16], DispatchError> {
+	let creator = funded_account::<T>("creator", 0);
+	let kitty_id = mint_kitty::<T>(&creator, 1_000)?;
+	Kittens::<T>::set_royalty(
+		RawOrigin::Signed(creator.clone()).into(),
+		kitty_id,
+		T::MaxRoyalty::get(),
+	)?;
+	Kittens::<T>::transfer(RawOrigin::Signed(creator).into(), owner.clone(), kitty_id)?;
+	Ok(kitty_id)
+}
+
+// the latest end block an auction started now can have
+fn auction_end<T: Config>() -> T::BlockNumber {
+	frame_system::Pallet::<T>::block_number() + T::MaxAuctionDuration::get()
+}
+
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	create_kitty {
+		let n in 0 .. T::MaxKittiesOwned::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		mint_kitties::<T>(&caller, 0, n)?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(OwnedCount::<T>::get(&caller), n + 1);
+	}
+
+	transfer {
+		let n in 0 .. T::MaxKittiesOwned::get() - 1;
+		let o in 0 .. T::MaxOffersPerKitty::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		mint_kitties::<T>(&recipient, 1, n)?;
+		Kittens::<T>::approve(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty_id,
+			Some(recipient.clone()),
+		)?;
+		make_offers::<T>(kitty_id, o)?;
+	}: _(RawOrigin::Signed(caller), recipient.clone(), kitty_id)
+	verify {
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().owner, recipient);
+	}
+
+	set_price {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		// replace an earlier expiry with one in a block where all other listings expire
+		let now = frame_system::Pallet::<T>::block_number();
+		let expiry = now + 10u32.into();
+		Kittens::<T>::set_price(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty_id,
+			Some(price::<T>()),
+			Some(now + 5u32.into()),
+		)?;
+		for i in 1 .. T::MaxListingExpiries::get() {
+			let seller: T::AccountId = account("seller", i, SEED);
+			let other = mint_kitty::<T>(&seller, i)?;
+			Kittens::<T>::set_price(
+				RawOrigin::Signed(seller).into(),
+				other,
+				Some(price::<T>()),
+				Some(expiry),
+			)?;
+		}
+	}: _(RawOrigin::Signed(caller), kitty_id, Some(price::<T>()), Some(expiry))
+	verify {
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().price, Some(price::<T>()));
+		assert_eq!(ListingExpiry::<T>::get(&kitty_id), Some(expiry));
+	}
+
+	buy_kitty {
+		let n in 0 .. T::MaxKittiesOwned::get() - 1;
+		let o in 0 .. T::MaxOffersPerKitty::get();
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = royalty_kitty::<T>(&seller)?;
+		Kittens::<T>::set_price(
+			RawOrigin::Signed(seller).into(),
+			kitty_id,
+			Some(price::<T>()),
+			None,
+		)?;
+		make_offers::<T>(kitty_id, o)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		mint_kitties::<T>(&caller, 0, n)?;
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price::<T>())
+	verify {
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().owner, caller);
+	}
+
+	breed_kitty {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let mom = mint_kitty::<T>(&caller, 1)?;
+		let dad = mint_kitty::<T>(&caller, 2)?;
+	}: _(RawOrigin::Signed(caller.clone()), mom, dad)
+	verify {
+		assert_eq!(OwnedCount::<T>::get(&caller), 3);
+	}
+
+	start_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+	}: _(RawOrigin::Signed(caller), kitty_id, price::<T>(), auction_end::<T>())
+	verify {
+		assert!(Auctions::<T>::contains_key(&kitty_id));
+	}
+
+	bid {
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = mint_kitty::<T>(&seller, 0)?;
+		Kittens::<T>::start_auction(
+			RawOrigin::Signed(seller).into(),
+			kitty_id,
+			price::<T>(),
+			auction_end::<T>(),
+		)?;
+		// the new bid releases the one it beats
+		let bidder = funded_account::<T>("bidder", 0);
+		Kittens::<T>::bid(RawOrigin::Signed(bidder).into(), kitty_id, price::<T>())?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let amount = price::<T>() * 2u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, amount)
+	verify {
+		assert_eq!(Auctions::<T>::get(&kitty_id).unwrap().best_bid, Some((caller, amount)));
+	}
+
+	cancel_auction {
+		let a in 1 .. T::MaxAuctionsPerBlock::get();
+		let caller: T::AccountId = whitelisted_caller();
+		// every auction ends in the same block, the cancelled one is the last to be found
+		let mut kitty_id = [0u8; 16];
+		for seed in 0..a {
+			kitty_id = mint_kitty::<T>(&caller, seed)?;
+			Kittens::<T>::start_auction(
+				RawOrigin::Signed(caller.clone()).into(),
+				kitty_id,
+				price::<T>(),
+				auction_end::<T>(),
+			)?;
+		}
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(!Auctions::<T>::contains_key(&kitty_id));
+	}
+
+	start_dutch_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+	}: _(
+		RawOrigin::Signed(caller),
+		kitty_id,
+		price::<T>() * 2u32.into(),
+		price::<T>(),
+		auction_end::<T>(),
+	)
+	verify {
+		assert!(DutchAuctions::<T>::contains_key(&kitty_id));
+	}
+
+	make_offer {
+		let o in 0 .. T::MaxOffersPerKitty::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let kitty_id = mint_kitty::<T>(&owner, 0)?;
+		// with every slot taken, the lowest offer makes room for the caller's
+		make_offers::<T>(kitty_id, o)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let amount = price::<T>() * 2u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, amount, expiry)
+	verify {
+		assert!(Offers::<T>::contains_key(&kitty_id, &caller));
+	}
+
+	withdraw_offer {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let kitty_id = mint_kitty::<T>(&owner, 0)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Kittens::<T>::make_offer(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty_id,
+			price::<T>(),
+			expiry,
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert!(!Offers::<T>::contains_key(&kitty_id, &caller));
+	}
+
+	reject_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		let bidder = funded_account::<T>("bidder", 0);
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Kittens::<T>::make_offer(
+			RawOrigin::Signed(bidder.clone()).into(),
+			kitty_id,
+			price::<T>(),
+			expiry,
+		)?;
+	}: _(RawOrigin::Signed(caller), kitty_id, bidder.clone())
+	verify {
+		assert!(!Offers::<T>::contains_key(&kitty_id, &bidder));
+	}
+
+	accept_offer {
+		let o in 1 .. T::MaxOffersPerKitty::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let kitty_id = royalty_kitty::<T>(&caller)?;
+		make_offers::<T>(kitty_id, o)?;
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+	}: _(RawOrigin::Signed(caller), kitty_id, bidder.clone())
+	verify {
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().owner, bidder);
+	}
+
+	set_royalty {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+	}: _(RawOrigin::Signed(caller), kitty_id, T::MaxRoyalty::get())
+	verify {
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().royalty, T::MaxRoyalty::get());
+	}
+
+	approve {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		let operator: T::AccountId = account("operator", 0, SEED);
+	}: _(RawOrigin::Signed(caller), kitty_id, Some(operator.clone()))
+	verify {
+		assert_eq!(Approvals::<T>::get(&kitty_id), Some(operator));
+	}
+
+	set_approval_for_all {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone(), true)
+	verify {
+		assert!(OperatorApprovals::<T>::contains_key(&caller, &operator));
+	}
+
+	burn_kitty {
+		let o in 0 .. T::MaxOffersPerKitty::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		Kittens::<T>::start_auction(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty_id,
+			price::<T>(),
+			auction_end::<T>(),
+		)?;
+		make_offers::<T>(kitty_id, o)?;
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(!Kitties::<T>::contains_key(&kitty_id));
+	}
+
+	force_burn {
+		let o in 0 .. T::MaxOffersPerKitty::get();
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = mint_kitty::<T>(&owner, 0)?;
+		Kittens::<T>::start_auction(
+			RawOrigin::Signed(owner).into(),
+			kitty_id,
+			price::<T>(),
+			auction_end::<T>(),
+		)?;
+		let bidder = funded_account::<T>("auction bidder", 0);
+		Kittens::<T>::bid(RawOrigin::Signed(bidder).into(), kitty_id, price::<T>())?;
+		make_offers::<T>(kitty_id, o)?;
+	}: _(RawOrigin::Root, kitty_id)
+	verify {
+		assert!(!Kitties::<T>::contains_key(&kitty_id));
+	}
+
+	offer_rental {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+	}: _(RawOrigin::Signed(caller), kitty_id, price::<T>(), 10u32.into())
+	verify {
+		assert!(RentalOffers::<T>::contains_key(&kitty_id));
+	}
+
+	withdraw_rental_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		Kittens::<T>::offer_rental(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty_id,
+			price::<T>(),
+			10u32.into(),
+		)?;
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(!RentalOffers::<T>::contains_key(&kitty_id));
+	}
+
+	rent {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = mint_kitty::<T>(&owner, 0)?;
+		Kittens::<T>::offer_rental(
+			RawOrigin::Signed(owner).into(),
+			kitty_id,
+			price::<T>(),
+			10u32.into(),
+		)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let max_fee = price::<T>() * 10u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, 10u32.into(), max_fee)
+	verify {
+		assert_eq!(Kittens::<T>::renter_of(&kitty_id), Some(caller));
+	}
+
+	propose_swap {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let wanted = mint_kitty::<T>(&owner, 1)?;
+	}: _(RawOrigin::Signed(caller), kitty_id, wanted, Some(price::<T>()))
+	verify {
+		assert!(Swaps::<T>::contains_key(&kitty_id));
+	}
+
+	cancel_swap {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let wanted = mint_kitty::<T>(&owner, 1)?;
+		Kittens::<T>::propose_swap(RawOrigin::Signed(caller.clone()).into(), kitty_id, wanted, None)?;
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(!Swaps::<T>::contains_key(&kitty_id));
+	}
+
+	accept_swap {
+		let o in 0 .. T::MaxOffersPerKitty::get();
+		let proposer = funded_account::<T>("proposer", 0);
+		let kitty_id = mint_kitty::<T>(&proposer, 0)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let wanted = mint_kitty::<T>(&caller, 1)?;
+		Kittens::<T>::propose_swap(
+			RawOrigin::Signed(proposer.clone()).into(),
+			kitty_id,
+			wanted,
+			Some(price::<T>()),
+		)?;
+		make_offers::<T>(kitty_id, o)?;
+		make_offers::<T>(wanted, o)?;
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().owner, caller);
+		assert_eq!(Kitties::<T>::get(&wanted).unwrap().owner, proposer);
+	}
+
+	set_kitty_name {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		let name = vec![b'k'; T::MaxNameLength::get() as usize];
+	}: _(RawOrigin::Signed(caller), kitty_id, name.clone())
+	verify {
+		assert_eq!(Kittens::<T>::kitty_name(&kitty_id), Some(name));
+	}
+
+	clear_kitty_name {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		let name = vec![b'k'; T::MaxNameLength::get() as usize];
+		Kittens::<T>::set_kitty_name(RawOrigin::Signed(caller.clone()).into(), kitty_id, name)?;
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(!KittyNames::<T>::contains_key(&kitty_id));
+	}
+
+	force_clear_name {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = mint_kitty::<T>(&owner, 0)?;
+		let name = vec![b'k'; T::MaxNameLength::get() as usize];
+		Kittens::<T>::set_kitty_name(RawOrigin::Signed(owner).into(), kitty_id, name)?;
+	}: _(RawOrigin::Root, kitty_id)
+	verify {
+		assert!(!KittyNames::<T>::contains_key(&kitty_id));
+	}
+
+	batch_transfer {
+		let b in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		// every kitty goes to its own recipient and releases the most offers
+		let mut transfers: Vec<(T::AccountId, [u8; 16])> = Vec::new();
+		for i in 0..b {
+			let kitty_id = mint_kitty::<T>(&caller, i)?;
+			make_offers::<T>(kitty_id, T::MaxOffersPerKitty::get())?;
+			transfers.push((account("recipient", i, SEED), kitty_id));
+		}
+		let (recipient, kitty_id) = transfers[transfers.len() - 1].clone();
+	}: _(RawOrigin::Signed(caller), transfers)
+	verify {
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().owner, recipient);
+	}
+
+	batch_mint {
+		let b in 1 .. T::MaxBatchSize::get();
+		let kitties: Vec<(T::AccountId, [u8; 16])> =
+			(0..b).map(|i| (account("recipient", i, SEED), dna(i))).collect();
+	}: _(RawOrigin::Root, kitties)
+	verify {
+		assert!(Kitties::<T>::contains_key(dna(b - 1)));
+	}
+
+	lock_kitty {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = mint_kitty::<T>(&caller, 0)?;
+		// the listing is withdrawn along with its expiry
+		let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Kittens::<T>::set_price(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty_id,
+			Some(price::<T>()),
+			Some(until),
+		)?;
+	}: _(RawOrigin::Signed(caller), kitty_id, until)
+	verify {
+		assert_eq!(Kittens::<T>::lock_of(&kitty_id), Some(Lock::Until(until)));
+	}
+
+	soulbind {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let kitty_id = mint_kitty::<T>(&owner, 0)?;
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Kittens::<T>::set_price(
+			RawOrigin::Signed(owner).into(),
+			kitty_id,
+			Some(price::<T>()),
+			Some(expiry),
+		)?;
+	}: _(RawOrigin::Root, kitty_id)
+	verify {
+		assert_eq!(Kittens::<T>::lock_of(&kitty_id), Some(Lock::Soulbound));
+	}
+
+	commit_mint {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let hash = Kittens::<T>::mint_commitment(&caller, &[7; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), hash)
+	verify {
+		assert!(MintCommits::<T>::contains_key(&caller));
+	}
+
+	reveal_mint {
+		let n in 0 .. T::MaxKittiesOwned::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		mint_kitties::<T>(&caller, 0, n)?;
+		let hash = Kittens::<T>::mint_commitment(&caller, &[7; 32]);
+		Kittens::<T>::commit_mint(RawOrigin::Signed(caller.clone()).into(), hash)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::RevealDelay::get(),
+		);
+	}: _(RawOrigin::Signed(caller.clone()), [7; 32])
+	verify {
+		assert_eq!(OwnedCount::<T>::get(&caller), n + 1);
+		assert!(!MintCommits::<T>::contains_key(&caller));
+	}
+
+	on_initialize {
+		let a in 0 .. T::MaxAuctionsPerBlock::get();
+		let r in 0 .. T::MaxRentalsPerBlock::get();
+		let l in 0 .. T::MaxListingExpiries::get();
+		let c in 0 .. T::MaxCommitsPerBlock::get();
+		// everything ends in the block the commitments made now expire in
+		let now = frame_system::Pallet::<T>::block_number();
+		let end = now + T::RevealDelay::get() + T::RevealWindow::get();
+		// settled auctions pay a royalty and release every offer on the kitty
+		for i in 0..a {
+			let creator = funded_account::<T>("creator", i);
+			let seller = funded_account::<T>("seller", i);
+			let kitty_id = mint_kitty::<T>(&creator, i)?;
+			Kittens::<T>::set_royalty(
+				RawOrigin::Signed(creator.clone()).into(),
+				kitty_id,
+				T::MaxRoyalty::get(),
+			)?;
+			Kittens::<T>::transfer(RawOrigin::Signed(creator).into(), seller.clone(), kitty_id)?;
+			make_offers::<T>(kitty_id, T::MaxOffersPerKitty::get())?;
+			Kittens::<T>::start_auction(
+				RawOrigin::Signed(seller).into(),
+				kitty_id,
+				price::<T>(),
+				end,
+			)?;
+			let winner = funded_account::<T>("winner", i);
+			Kittens::<T>::bid(RawOrigin::Signed(winner).into(), kitty_id, price::<T>())?;
+		}
+		for i in 0..r {
+			let owner: T::AccountId = account("owner", i, SEED);
+			let kitty_id = mint_kitty::<T>(&owner, 100 + i)?;
+			Kittens::<T>::offer_rental(
+				RawOrigin::Signed(owner).into(),
+				kitty_id,
+				price::<T>(),
+				end - now,
+			)?;
+			let renter = funded_account::<T>("renter", i);
+			Kittens::<T>::rent(
+				RawOrigin::Signed(renter).into(),
+				kitty_id,
+				end - now,
+				BalanceOf::<T>::max_value(),
+			)?;
+		}
+		// each listing expires in a block of its own, all of them behind the cursor
+		for i in 0..l {
+			let seller: T::AccountId = account("lister", i, SEED);
+			let kitty_id = mint_kitty::<T>(&seller, 200 + i)?;
+			Kittens::<T>::set_price(
+				RawOrigin::Signed(seller).into(),
+				kitty_id,
+				Some(price::<T>()),
+				Some(end - i.into()),
+			)?;
+		}
+		ExpiryCursor::<T>::put(end - l.saturating_sub(1).into());
+		for i in 0..c {
+			let committer = funded_account::<T>("committer", i);
+			let hash = Kittens::<T>::mint_commitment(&committer, &[7; 32]);
+			Kittens::<T>::commit_mint(RawOrigin::Signed(committer).into(), hash)?;
+		}
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: {
+		Kittens::<T>::on_initialize(end);
+	}
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert!(Auctions::<T>::iter().next().is_none());
+		assert!(Rentals::<T>::iter().next().is_none());
+		assert!(ListingExpiry::<T>::iter().next().is_none());
+		assert!(MintCommits::<T>::iter().next().is_none());
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Kittens, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,99 @@
+// Deterministic decoding of kitty dna into named traits.
+//
+// The decoder only reads the dna, so the runtime, the node and any client that ports it derive
+// the same traits for a kitty. The lowest bit of the first byte is the gender, the next three
+// bytes are the fur colour, eye shape and pattern genes. Each gene maps onto its variants by
+// value ranges so rarer variants cover fewer values, and the rarity score adds up how rare each
+// trait of the kitty is.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(frame_support::Serialize, frame_support::Deserialize))]
+#[cfg_attr(feature = "std", serde(crate = "frame_support::serde"))]
+pub enum FurColour {
+	Black,
+	White,
+	Ginger,
+	Grey,
+	Cream,
+	Blue,
+	Calico,
+}
+
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(frame_support::Serialize, frame_support::Deserialize))]
+#[cfg_attr(feature = "std", serde(crate = "frame_support::serde"))]
+pub enum EyeShape {
+	Round,
+	Almond,
+	Slanted,
+	OddEyed,
+}
+
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(frame_support::Serialize, frame_support::Deserialize))]
+#[cfg_attr(feature = "std", serde(crate = "frame_support::serde"))]
+pub enum Pattern {
+	Solid,
+	Striped,
+	Spotted,
+	Bicolour,
+	Tortoiseshell,
+}
+
+/// The traits encoded in a kitty's dna.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(frame_support::Serialize, frame_support::Deserialize))]
+#[cfg_attr(feature = "std", serde(crate = "frame_support::serde", rename_all = "camelCase"))]
+pub struct Traits {
+	pub fur: FurColour,
+	pub eyes: EyeShape,
+	pub pattern: Pattern,
+	/// Sum of the rarity points of every trait, from 3 for the most common kitties up to 35.
+	pub rarity: u8,
+}
+
+// (highest gene value, variant, rarity points) for every variant, in ascending gene values
+const FUR: [(u8, FurColour, u8); 7] = [
+	(63, FurColour::Black, 1),
+	(127, FurColour::White, 1),
+	(175, FurColour::Ginger, 2),
+	(215, FurColour::Grey, 3),
+	(239, FurColour::Cream, 5),
+	(251, FurColour::Blue, 8),
+	(255, FurColour::Calico, 13),
+];
+
+const EYES: [(u8, EyeShape, u8); 4] = [
+	(127, EyeShape::Round, 1),
+	(199, EyeShape::Almond, 2),
+	(239, EyeShape::Slanted, 4),
+	(255, EyeShape::OddEyed, 10),
+];
+
+const PATTERN: [(u8, Pattern, u8); 5] = [
+	(95, Pattern::Solid, 1),
+	(175, Pattern::Striped, 2),
+	(223, Pattern::Spotted, 3),
+	(247, Pattern::Bicolour, 6),
+	(255, Pattern::Tortoiseshell, 12),
+];
+
+// the variant whose range holds `gene`, with its rarity points
+fn express<V: Copy>(gene: u8, table: &[(u8, V, u8)]) -> (V, u8) {
+	let (_, variant, points) =
+		table.iter().find(|(max, ..)| gene <= *max).unwrap_or(&table[table.len() - 1]);
+	(*variant, *points)
+}
+
+/// Decode the traits of a kitty from its dna.
+pub fn decode(dna: &[u8; 16]) -> Traits {
+	let (fur, fur_points) = express(dna[1], &FUR);
+	let (eyes, eye_points) = express(dna[2], &EYES);
+	let (pattern, pattern_points) = express(dna[3], &PATTERN);
+
+	Traits { fur, eyes, pattern, rarity: fur_points + eye_points + pattern_points }
+}
@@ -0,0 +1,65 @@
+// Implementations of the FRAME `nonfungible` token traits, so other pallets can hold, move and
+// mint kitties without depending on this pallet directly.
+
+use crate::{Config, Kitties, OwnedKitties, Pallet, Rentals};
+use codec::Encode;
+use frame_support::{
+	dispatch::DispatchResult,
+	sp_std::{boxed::Box, vec::Vec},
+	traits::tokens::nonfungible::{Inspect, InspectEnumerable, Mutate, Transfer},
+};
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+	type ItemId = [u8; 16];
+
+	fn owner(item: &[u8; 16]) -> Option<T::AccountId> {
+		Kitties::<T>::get(item).map(|kitty| kitty.owner)
+	}
+
+	/// Kitty attributes are read only and SCALE encoded: `b"dna"` gives the `[u8; 16]` dna,
+	/// `b"gender"` the `Gender` and `b"price"` the `Option<Balance>` asking price.
+	fn attribute(item: &[u8; 16], key: &[u8]) -> Option<Vec<u8>> {
+		let kitty = Kitties::<T>::get(item)?;
+		match key {
+			b"dna" => Some(kitty.dna.encode()),
+			b"gender" => Some(kitty.gender.encode()),
+			b"price" => Some(kitty.price.encode()),
+			_ => None,
+		}
+	}
+
+	// locked kitties stay with their owner, auctioned and rented ones until the auction or
+	// rental ends
+	fn can_transfer(item: &[u8; 16]) -> bool {
+		Kitties::<T>::get(item).map_or(false, |kitty| !Self::is_locked(&kitty)) &&
+			!Self::is_auctioned(item) &&
+			!Rentals::<T>::contains_key(item)
+	}
+}
+
+impl<T: Config> InspectEnumerable<T::AccountId> for Pallet<T> {
+	fn items() -> Box<dyn Iterator<Item = [u8; 16]>> {
+		Box::new(Kitties::<T>::iter_keys())
+	}
+
+	fn owned(who: &T::AccountId) -> Box<dyn Iterator<Item = [u8; 16]>> {
+		Box::new(OwnedKitties::<T>::iter_key_prefix(who))
+	}
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+	/// Mint a generation zero kitty whose dna is the item id.
+	fn mint_into(item: &[u8; 16], who: &T::AccountId) -> DispatchResult {
+		Self::mint(who, *item, Self::gender_of(item)).map(|_| ())
+	}
+
+	fn burn_from(item: &[u8; 16]) -> DispatchResult {
+		Self::do_burn(*item)
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(item: &[u8; 16], destination: &T::AccountId) -> DispatchResult {
+		Self::do_transfer(*item, destination.clone())
+	}
+}
@@ -2,32 +2,85 @@
 
 pub use pallet::*;
 
+// traits decoded from kitty dna
+pub mod genome;
+
+// storage migrations
+pub mod migrations;
+
+// `nonfungible` token traits
+mod impl_nonfungible;
+
+// mocks for test
+#[cfg(test)]
+mod mock;
+
+// tests
+#[cfg(test)]
+mod tests;
+
+// benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+// weights of the calls
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
+	use super::WeightInfo;
+	use crate::genome::{self, FurColour};
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
-	use frame_support::traits::{Currency, Randomness};
+	use frame_support::{
+		sp_runtime::{
+			traits::{AccountIdConversion, SaturatedConversion, Saturating, Zero},
+			Perbill, Permill,
+		},
+		sp_std::vec::Vec,
+		storage::{with_transaction, TransactionOutcome},
+		traits::{
+			BalanceStatus, Currency, ExistenceRequirement, OnUnbalanced, Randomness,
+			ReservableCurrency, StorageVersion,
+		},
+		transactional, PalletId,
+	};
 
 	// current version of storage
-	// FIXME const STORAGE_VERSION: dyn GetStorageVersion =
-	// frame_support::traits::StorageVersion::new(106);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	// TODO get hands dirty and get clean idea on storage_version
-	// FIXME #[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	type BalanceOf<T> =
+	pub(crate) type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 
 	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(frame_support::Serialize, frame_support::Deserialize))]
+	#[cfg_attr(feature = "std", serde(crate = "frame_support::serde"))]
 	pub enum Gender {
 		Male,
 		Female,
 	}
 
+	// Keeps a kitty with its owner, no transfer, sale or listing is possible while in force
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(frame_support::Serialize, frame_support::Deserialize))]
+	#[cfg_attr(feature = "std", serde(crate = "frame_support::serde"))]
+	pub enum Lock<BlockNumber> {
+		// set by the owner, lifted at the given block
+		Until(BlockNumber),
+		// set by root for soulbound rewards, never lifted
+		Soulbound,
+	}
+
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Copy)]
 	#[scale_info(skip_type_params(T))]
 	#[codec(mel_bound())]
@@ -36,42 +89,544 @@ pub mod pallet {
 		pub price: Option<BalanceOf<T>>,
 		pub gender: Gender,
 		pub owner: T::AccountId,
+		// dna of the (mom, dad) this kitty was bred from, `None` for minted kitties
+		pub parents: Option<([u8; 16], [u8; 16])>,
+		// number of breeding steps away from a minted kitty
+		pub generation: u32,
+		// account that minted or bred the kitty
+		pub creator: T::AccountId,
+		// share of every sale price paid to the creator
+		pub royalty: Permill,
+		pub lock: Option<Lock<T::BlockNumber>>,
+	}
+
+	// A running english auction for a kitty
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Auction<T: Config> {
+		pub seller: T::AccountId,
+		// lowest bid the seller is willing to accept
+		pub reserve_price: BalanceOf<T>,
+		// block in which the auction is settled, bids are accepted up to the block before
+		pub end: T::BlockNumber,
+		// highest bidder so far and their bid, which is held in reserve
+		pub best_bid: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	// A descending price listing, the price falls linearly from `start_price` at block `start`
+	// towards `floor_price` at block `end`, where the listing expires
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct DutchAuction<T: Config> {
+		pub seller: T::AccountId,
+		pub start_price: BalanceOf<T>,
+		pub floor_price: BalanceOf<T>,
+		pub start: T::BlockNumber,
+		pub end: T::BlockNumber,
+	}
+
+	// An open offer on a kitty, the amount is held in reserve on the bidder's account
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Offer<T: Config> {
+		pub amount: BalanceOf<T>,
+		// the offer can no longer be accepted from this block on
+		pub expiry: T::BlockNumber,
+	}
+
+	// Terms on which the owner lends a kitty out
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct RentalOffer<T: Config> {
+		pub fee_per_block: BalanceOf<T>,
+		// longest rental the owner accepts, in blocks
+		pub max_duration: T::BlockNumber,
+	}
+
+	// A running rental, the renter holds the usage rights of the kitty until block `end`
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Rental<T: Config> {
+		pub renter: T::AccountId,
+		pub end: T::BlockNumber,
+	}
+
+	// A proposal to trade the kitty it is stored under for the `wanted` kitty
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Swap<T: Config> {
+		pub proposer: T::AccountId,
+		pub wanted: [u8; 16],
+		// paid by the proposer to the owner of `wanted` on top of the kitty
+		pub top_up: Option<BalanceOf<T>>,
+	}
+
+	// A completed sale of a kitty
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Sale<T: Config> {
+		pub price: BalanceOf<T>,
+		pub block: T::BlockNumber,
+	}
+
+	// Sales of the kitties of one fur colour. The volume is summed over consecutive periods of
+	// `StatsPeriod` blocks, keeping the current and the previous period
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct MarketStats<T: Config> {
+		// first block of the current period
+		pub period_start: T::BlockNumber,
+		pub volume: BalanceOf<T>,
+		pub previous_volume: BalanceOf<T>,
+		// number of sales ever made
+		pub sales: u32,
+		pub last_price: BalanceOf<T>,
+		pub last_sale: T::BlockNumber,
+	}
+
+	// A commitment to mint a kitty, revealed with the secret it hashes
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct MintCommit<T: Config> {
+		// `mint_commitment` of the committer and their secret
+		pub hash: [u8; 32],
+		// reserved from the committer until the commitment is revealed or expires
+		pub deposit: BalanceOf<T>,
+		// first block the secret can be revealed in
+		pub reveal_from: T::BlockNumber,
+		// block at which the commitment expires unrevealed
+		pub expires: T::BlockNumber,
 	}
 
 	#[pallet::storage]
 	pub(super) type CountForKitties<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	// kitties created or bred in the current block, bounded by `MaxMintsPerBlock`
+	#[pallet::storage]
+	pub(super) type MintedInBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::storage]
 	pub(super) type Kitties<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], Kitty<T>>;
 
+	// kitties held by each account
+	#[pallet::storage]
+	pub(super) type OwnedKitties<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, [u8; 16], ()>;
+
+	// number of kitties held by each account, bounded by `MaxKittiesOwned`
+	#[pallet::storage]
+	pub(super) type OwnedCount<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	// block from which a kitty is allowed to breed again
+	#[pallet::storage]
+	pub(super) type BreedingCooldowns<T: Config> =
+		StorageMap<_, Twox64Concat, [u8; 16], T::BlockNumber>;
+
+	#[pallet::storage]
+	pub(super) type Auctions<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], Auction<T>>;
+
+	#[pallet::storage]
+	pub(super) type DutchAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, [u8; 16], DutchAuction<T>>;
+
+	// kitties whose english or dutch auction is closed at a given block
+	#[pallet::storage]
+	pub(super) type AuctionsEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<[u8; 16], T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	// offers by kitty and bidder
 	#[pallet::storage]
-	pub(super) type KittiesOwned<T: Config> = StorageMap<
+	pub(super) type Offers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, [u8; 16], Twox64Concat, T::AccountId, Offer<T>>;
+
+	// number of open offers per kitty, bounded by `MaxOffersPerKitty`
+	#[pallet::storage]
+	pub(super) type OfferCount<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], u32, ValueQuery>;
+
+	// account approved to transfer a single kitty, cleared whenever the kitty changes hands
+	#[pallet::storage]
+	pub(super) type Approvals<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], T::AccountId>;
+
+	// operators approved to transfer every kitty of an owner
+	#[pallet::storage]
+	pub(super) type OperatorApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, ()>;
+
+	// rental terms offered by the owner, cleared whenever the kitty changes hands
+	#[pallet::storage]
+	pub(super) type RentalOffers<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], RentalOffer<T>>;
+
+	#[pallet::storage]
+	pub(super) type Rentals<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], Rental<T>>;
+
+	// kitties whose rental ends at a given block
+	#[pallet::storage]
+	pub(super) type RentalsEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<[u8; 16], T::MaxRentalsPerBlock>,
+		ValueQuery,
+	>;
+
+	// swap proposals by offered kitty, cleared whenever the kitty changes hands
+	#[pallet::storage]
+	pub(super) type Swaps<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], Swap<T>>;
+
+	// name of a kitty and the deposit reserved for it from the owner
+	#[pallet::storage]
+	pub(super) type KittyNames<T: Config> =
+		StorageMap<_, Twox64Concat, [u8; 16], (BoundedVec<u8, T::MaxNameLength>, BalanceOf<T>)>;
+
+	// block at which a fixed price listing is withdrawn
+	#[pallet::storage]
+	pub(super) type ListingExpiry<T: Config> =
+		StorageMap<_, Twox64Concat, [u8; 16], T::BlockNumber>;
+
+	// kitties whose fixed price listing expires at a given block
+	#[pallet::storage]
+	pub(super) type ListingsExpiring<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<[u8; 16], T::MaxListingExpiries>,
+		ValueQuery,
+	>;
+
+	// first block whose expired listings are not all withdrawn yet
+	#[pallet::storage]
+	pub(super) type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber>;
+
+	// kitties with a fixed price or in a dutch auction, which `kitties_for_sale` pages over
+	#[pallet::storage]
+	pub(super) type ForSale<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], ()>;
+
+	// pending mint commitment of every account
+	#[pallet::storage]
+	pub(super) type MintCommits<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, MintCommit<T>>;
+
+	// accounts whose mint commitment expires at a given block
+	#[pallet::storage]
+	pub(super) type CommitsExpiring<T: Config> = StorageMap<
 		_,
 		Twox64Concat,
-		T::AccountId,
-		BoundedVec<[u8; 16], T::MaxKittiesOwned>,
+		T::BlockNumber,
+		BoundedVec<T::AccountId, T::MaxCommitsPerBlock>,
 		ValueQuery,
 	>;
 
+	// latest sales of every kitty, oldest first, bounded by `MaxSaleHistory`
+	#[pallet::storage]
+	pub(super) type SaleHistory<T: Config> =
+		StorageMap<_, Twox64Concat, [u8; 16], BoundedVec<Sale<T>, T::MaxSaleHistory>, ValueQuery>;
+
+	// market statistics by fur colour, the trait class kitties are priced by
+	#[pallet::storage]
+	pub(super) type ClassStats<T: Config> = StorageMap<_, Twox64Concat, FurColour, MarketStats<T>>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		#[pallet::constant]
 		type MaxKittiesOwned: Get<u32>;
 
 		type KittyRandomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Number of blocks a kitty has to wait after breeding before it can breed again.
+		#[pallet::constant]
+		type BreedingCooldown: Get<Self::BlockNumber>;
+
+		/// Maximum number of auctions that can end in the same block.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+
+		/// Maximum number of blocks an auction can run for.
+		#[pallet::constant]
+		type MaxAuctionDuration: Get<Self::BlockNumber>;
+
+		/// Maximum number of open offers on a single kitty.
+		#[pallet::constant]
+		type MaxOffersPerKitty: Get<u32>;
+
+		/// Highest royalty a creator can ask on the sales of their kitties.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+
+		/// Share of every fixed price or dutch auction sale taken as a marketplace fee.
+		#[pallet::constant]
+		type MarketplaceFee: Get<Permill>;
+
+		/// Id of the pallet, marketplace fees are paid to the account derived from it.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of rentals that can end in the same block.
+		#[pallet::constant]
+		type MaxRentalsPerBlock: Get<u32>;
+
+		/// Maximum number of kitties in existence.
+		#[pallet::constant]
+		type MaxKittySupply: Get<u64>;
+
+		/// Maximum number of kitties that can be created or bred in the same block.
+		#[pallet::constant]
+		type MaxMintsPerBlock: Get<u32>;
+
+		/// Fee paid to the pallet account for every kitty created or bred, zero for none.
+		#[pallet::constant]
+		type MintFee: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved from the owner of a kitty while it has a name.
+		#[pallet::constant]
+		type NameDeposit: Get<BalanceOf<Self>>;
+
+		/// What to do with the deposit of a name cleared by root.
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Minimum length of a kitty name.
+		#[pallet::constant]
+		type MinNameLength: Get<u32>;
+
+		/// Maximum length of a kitty name.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// Number of past sales kept for every kitty.
+		#[pallet::constant]
+		type MaxSaleHistory: Get<u32>;
+
+		/// Number of blocks the rolling sales volume of a trait class is measured over.
+		#[pallet::constant]
+		type StatsPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum number of fixed price listings that can expire in the same block, also the
+		/// most listings withdrawn and expiry blocks looked at in one block.
+		#[pallet::constant]
+		type MaxListingExpiries: Get<u32>;
+
+		/// Maximum number of kitties transferred or minted in one batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Number of blocks after a mint commitment before its secret can be revealed, at least
+		/// two. The dna is seeded by the hash of the block before, unknown when committing.
+		#[pallet::constant]
+		type RevealDelay: Get<Self::BlockNumber>;
+
+		/// Number of blocks a mint commitment can be revealed in before it expires, at least
+		/// one and less than the `BlockHashCount` of the system pallet.
+		#[pallet::constant]
+		type RevealWindow: Get<Self::BlockNumber>;
+
+		/// Deposit reserved from the committer until a mint commitment is revealed, and
+		/// forfeited if the commitment expires unrevealed.
+		#[pallet::constant]
+		type CommitDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of mint commitments that can expire in the same block.
+		#[pallet::constant]
+		type MaxCommitsPerBlock: Get<u32>;
+
+		/// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		Created { kitty: [u8; 16], owner: T::AccountId },
-		Transferred { from: T::AccountId, to: T::AccountId, kitty: [u8; 16] },
-		PriceSet { kitty: [u8; 16], price: Option<BalanceOf<T>> },
-		Sold { seller: T::AccountId, buyer: T::AccountId, kitty: [u8; 16], price: BalanceOf<T> },
+		Created {
+			kitty: [u8; 16],
+			owner: T::AccountId,
+		},
+		Transferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			kitty: [u8; 16],
+		},
+		PriceSet {
+			kitty: [u8; 16],
+			price: Option<BalanceOf<T>>,
+			expiry: Option<T::BlockNumber>,
+		},
+		Sold {
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			kitty: [u8; 16],
+			price: BalanceOf<T>,
+			// part of the price paid to the marketplace account
+			fee: BalanceOf<T>,
+		},
+		Bred {
+			kitty: [u8; 16],
+			mom: [u8; 16],
+			dad: [u8; 16],
+			owner: T::AccountId,
+		},
+		AuctionStarted {
+			kitty: [u8; 16],
+			seller: T::AccountId,
+			reserve_price: BalanceOf<T>,
+			end: T::BlockNumber,
+		},
+		BidPlaced {
+			kitty: [u8; 16],
+			bidder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		AuctionSettled {
+			kitty: [u8; 16],
+			seller: T::AccountId,
+			winner: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		AuctionCancelled {
+			kitty: [u8; 16],
+		},
+		DutchAuctionStarted {
+			kitty: [u8; 16],
+			seller: T::AccountId,
+			start_price: BalanceOf<T>,
+			floor_price: BalanceOf<T>,
+			end: T::BlockNumber,
+		},
+		OfferMade {
+			kitty: [u8; 16],
+			bidder: T::AccountId,
+			amount: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		},
+		OfferWithdrawn {
+			kitty: [u8; 16],
+			bidder: T::AccountId,
+		},
+		OfferRejected {
+			kitty: [u8; 16],
+			bidder: T::AccountId,
+		},
+		// an expired or the lowest offer made room for a new one
+		OfferDropped {
+			kitty: [u8; 16],
+			bidder: T::AccountId,
+		},
+		OfferAccepted {
+			kitty: [u8; 16],
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		RoyaltySet {
+			kitty: [u8; 16],
+			royalty: Permill,
+		},
+		RoyaltyPaid {
+			kitty: [u8; 16],
+			creator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		Burned {
+			kitty: [u8; 16],
+			owner: T::AccountId,
+		},
+		Approval {
+			kitty: [u8; 16],
+			owner: T::AccountId,
+			approved: Option<T::AccountId>,
+		},
+		ApprovalForAll {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			approved: bool,
+		},
+		RentalOffered {
+			kitty: [u8; 16],
+			fee_per_block: BalanceOf<T>,
+			max_duration: T::BlockNumber,
+		},
+		RentalOfferWithdrawn {
+			kitty: [u8; 16],
+		},
+		Rented {
+			kitty: [u8; 16],
+			owner: T::AccountId,
+			renter: T::AccountId,
+			// paid upfront for the whole rental
+			fee: BalanceOf<T>,
+			end: T::BlockNumber,
+		},
+		RentalEnded {
+			kitty: [u8; 16],
+			renter: T::AccountId,
+		},
+		SwapProposed {
+			kitty: [u8; 16],
+			wanted: [u8; 16],
+			proposer: T::AccountId,
+			top_up: Option<BalanceOf<T>>,
+		},
+		SwapCancelled {
+			kitty: [u8; 16],
+		},
+		Swapped {
+			kitty: [u8; 16],
+			wanted: [u8; 16],
+			proposer: T::AccountId,
+			acceptor: T::AccountId,
+			top_up: Option<BalanceOf<T>>,
+		},
+		NameSet {
+			kitty: [u8; 16],
+		},
+		NameChanged {
+			kitty: [u8; 16],
+		},
+		NameCleared {
+			kitty: [u8; 16],
+			// returned to the owner
+			deposit: BalanceOf<T>,
+		},
+		NameKilled {
+			kitty: [u8; 16],
+			// slashed from the owner
+			deposit: BalanceOf<T>,
+		},
+		ListingExpired {
+			kitty: [u8; 16],
+		},
+		Locked {
+			kitty: [u8; 16],
+			until: T::BlockNumber,
+		},
+		Soulbound {
+			kitty: [u8; 16],
+		},
+		MintCommitted {
+			owner: T::AccountId,
+			expires: T::BlockNumber,
+		},
+		MintCommitExpired {
+			owner: T::AccountId,
+			// forfeited by the owner
+			deposit: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -84,6 +639,184 @@ pub mod pallet {
 		TransferToSelf,
 		BidPriceTooLow,
 		NotForSale,
+		/// Breeding needs a female mom and a male dad.
+		GenderMismatch,
+		/// One of the parents bred too recently.
+		BreedingCooldown,
+		/// The kitty is being auctioned.
+		InAuction,
+		/// The kitty is not being auctioned.
+		NotInAuction,
+		/// The auction end is in the past or too far in the future.
+		InvalidAuctionEnd,
+		/// Too many auctions end in the requested block.
+		TooManyAuctions,
+		/// The auction is over and no longer accepts bids.
+		AuctionEnded,
+		/// An auction that already has bids cannot be cancelled.
+		AuctionHasBids,
+		/// The floor price of a dutch auction is above its start price.
+		InvalidPriceRange,
+		/// There is no offer from this bidder on the kitty.
+		NoOffer,
+		/// The offer has expired.
+		OfferExpired,
+		/// The offer expiry is not in the future.
+		InvalidOfferExpiry,
+		/// The kitty already has the maximum number of open offers, none of them expired or
+		/// lower than the new one.
+		TooManyOffers,
+		/// The offered amount is below the existential deposit.
+		OfferTooLow,
+		/// The royalty is above `MaxRoyalty`.
+		RoyaltyTooHigh,
+		/// Only the creator of a kitty can set its royalty.
+		NotCreator,
+		/// An account cannot approve itself.
+		ApproveToSelf,
+		/// The kitty is rented out.
+		Rented,
+		/// The owner does not offer the kitty for rent.
+		NotForRent,
+		/// The rental duration is zero or longer than the owner allows.
+		InvalidRentalDuration,
+		/// Too many rentals end in the requested block.
+		TooManyRentals,
+		/// An owner cannot rent their own kitty.
+		RentToSelf,
+		/// There is no swap proposal for the kitty.
+		NoSwap,
+		/// The wanted kitty is already held by the proposer.
+		SwapWithSelf,
+		/// There are already `MaxKittySupply` kitties.
+		MaxSupplyReached,
+		/// `MaxMintsPerBlock` kitties were already created or bred in this block.
+		MintLimitReached,
+		/// The name is shorter than `MinNameLength`.
+		NameTooShort,
+		/// The name is longer than `MaxNameLength`.
+		NameTooLong,
+		/// The kitty has no name.
+		Unnamed,
+		/// The listing expiry is not in the future, or there is no price to expire.
+		InvalidListingExpiry,
+		/// Too many listings already expire in the requested block.
+		TooManyListings,
+		/// The batch holds more than `MaxBatchSize` items.
+		BatchTooLarge,
+		/// The kitty is locked with its owner.
+		Locked,
+		/// A lock has to end in the future and cannot be shortened.
+		InvalidLock,
+		/// The caller already has a mint commitment pending.
+		CommitPending,
+		/// The caller has no mint commitment.
+		NoCommit,
+		/// The mint commitment cannot be revealed before `RevealDelay` blocks passed.
+		RevealTooEarly,
+		/// The secret does not match the mint commitment.
+		BadReveal,
+		/// Too many mint commitments already expire in the same block.
+		TooManyCommits,
+		/// Less than the price is left in the buyer's reserve.
+		InsufficientReserve,
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		// (owner, dna, gender, price) of every kitty minted at genesis
+		pub kitties: Vec<(T::AccountId, [u8; 16], Gender, Option<BalanceOf<T>>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { kitties: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::fund_account();
+
+			for (owner, dna, gender, price) in &self.kitties {
+				// The gender of every other kitty follows from its dna
+				assert!(
+					Pallet::<T>::gender_of(dna) == *gender,
+					"genesis kitty gender does not match its dna"
+				);
+				Pallet::<T>::mint(owner, *dna, *gender).expect("genesis kitty cannot be minted");
+				if price.is_some() {
+					Kitties::<T>::mutate(dna, |kitty| {
+						if let Some(kitty) = kitty {
+							kitty.price = *price;
+						}
+					});
+					ForSale::<T>::insert(dna, ());
+				}
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// settle every auction ending in this block, expire dutch auctions, end rentals, withdraw
+		// expired listings, drop expired mint commitments and reset the mint limit
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			MintedInBlock::<T>::kill();
+
+			let ending = AuctionsEnding::<T>::take(n);
+			let auctions = ending.len() as u32;
+
+			for kitty_id in ending {
+				Self::settle_auction(kitty_id);
+			}
+
+			let rentals_ending = RentalsEnding::<T>::take(n);
+			let rentals = rentals_ending.len() as u32;
+
+			for kitty_id in rentals_ending {
+				if let Some(rental) = Rentals::<T>::take(&kitty_id) {
+					Self::deposit_event(Event::RentalEnded {
+						kitty: kitty_id,
+						renter: rental.renter,
+					});
+				}
+			}
+
+			let (blocks, expired) = Self::expire_listings(n);
+
+			let commits_ending = CommitsExpiring::<T>::take(n);
+			let commits = commits_ending.len() as u32;
+
+			for owner in commits_ending {
+				if let Some(commit) = MintCommits::<T>::take(&owner) {
+					// Letting a commitment expire to get another seed costs the deposit
+					let _ = T::Currency::slash_reserved(&owner, commit.deposit);
+					Self::deposit_event(Event::MintCommitExpired {
+						owner,
+						deposit: commit.deposit,
+					});
+				}
+			}
+
+			// Every listing was benchmarked in an expiry block of its own
+			T::WeightInfo::on_initialize(auctions, rentals, blocks.max(expired), commits)
+		}
+
+		fn integrity_test() {
+			// The hash seeding a revealed kitty follows the commitment and is kept until it expires
+			assert!(
+				T::RevealDelay::get() >= 2u32.into(),
+				"RevealDelay has to be at least two blocks"
+			);
+			assert!(!T::RevealWindow::get().is_zero(), "RevealWindow has to be at least one block");
+			assert!(
+				T::RevealWindow::get() < T::BlockHashCount::get(),
+				"RevealWindow has to be shorter than BlockHashCount"
+			);
+		}
 	}
 
 	// Pallet's callable functions.
@@ -91,11 +824,14 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Create a new unique kitty.
 		///
-		/// The actual kitty creation is done in the `mint()` function.
-		#[pallet::weight(0)]
+		/// The actual kitty creation is done in the `mint()` function. The caller pays the
+		/// `MintFee`.
+		#[pallet::weight(T::WeightInfo::create_kitty(T::MaxKittiesOwned::get()))]
+		#[transactional]
 		pub fn create_kitty(origin: OriginFor<T>) -> DispatchResult {
 			// Make sure the caller is from a signed origin
 			let sender = ensure_signed(origin)?;
+			Self::charge_mint(&sender)?;
 
 			// Generate unique DNA and Gender using a helper function
 			let (kitty_gen_dna, gender) = Self::gen_dna();
@@ -108,9 +844,13 @@ pub mod pallet {
 
 		/// Directly transfer a kitty to another recipient.
 		///
-		/// Any account that holds a kitty can send it to another Account. This will reset the
-		/// asking price of the kitty, marking it not for sale.
-		#[pallet::weight(0)]
+		/// Any account that holds a kitty can send it to another Account, and so can accounts
+		/// the owner approved for it. This will reset the asking price of the kitty, marking it
+		/// not for sale.
+		#[pallet::weight(T::WeightInfo::transfer(
+			T::MaxKittiesOwned::get(),
+			T::MaxOffersPerKitty::get()
+		))]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			to: T::AccountId,
@@ -123,8 +863,11 @@ pub mod pallet {
 			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
 
 			// if kitty is found make  sure signer is the owner of the kitty inteded to be
-			// transferred
-			ensure!(kitty.owner == from, Error::<T>::NotOwner);
+			// transferred, or approved by the owner
+			ensure!(
+				Self::is_approved_or_owner(&from, &kitty_id, &kitty.owner),
+				Error::<T>::NotOwner
+			);
 
 			// transfer the kitty
 			Self::do_transfer(kitty_id, to)?;
@@ -133,12 +876,15 @@ pub mod pallet {
 
 		/// Set the price for a kitty.
 		///
-		/// Updates kitty price and updates storage.
-		#[pallet::weight(0)]
+		/// Updates kitty price and updates storage. With an `expiry` block the kitty is unlisted
+		/// again at the start of that block.
+		#[pallet::weight(T::WeightInfo::set_price())]
+		#[transactional]
 		pub fn set_price(
 			origin: OriginFor<T>,
 			kitty_id: [u8; 16],
 			new_price: Option<BalanceOf<T>>,
+			expiry: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			// Make sure the caller is from a signed origin
 			let sender = ensure_signed(origin)?;
@@ -146,108 +892,1101 @@ pub mod pallet {
 			// Ensure the kitty exists and is called by the kitty owner
 			let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
 			ensure!(kitty.owner == sender, Error::<T>::NotOwner);
+			ensure!(!Self::is_auctioned(&kitty_id), Error::<T>::InAuction);
+			ensure!(!Self::is_locked(&kitty), Error::<T>::Locked);
+			if let Some(expiry) = expiry {
+				ensure!(
+					new_price.is_some() && expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidListingExpiry
+				);
+			}
+
+			// Replace the expiry of the previous listing
+			Self::unschedule_listing_expiry(&kitty_id);
+			if let Some(expiry) = expiry {
+				ListingsExpiring::<T>::try_append(expiry, kitty_id)
+					.map_err(|_| Error::<T>::TooManyListings)?;
+				ListingExpiry::<T>::insert(&kitty_id, expiry);
+			}
 
 			// Set the price in storage
 			kitty.price = new_price;
 			Kitties::<T>::insert(&kitty_id, kitty);
+			if new_price.is_some() {
+				ForSale::<T>::insert(&kitty_id, ());
+			} else {
+				ForSale::<T>::remove(&kitty_id);
+			}
 
 			// Deposit a "PriceSet" event.
-			Self::deposit_event(Event::PriceSet { kitty: kitty_id, price: new_price });
+			Self::deposit_event(Event::PriceSet { kitty: kitty_id, price: new_price, expiry });
 
 			Ok(())
 		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		// generate random DNA string for kitty
-		fn gen_dna() -> ([u8; 16], Gender) {
-			let random = T::KittyRandomness::random(&b"dna"[..]).0;
 
-			let unique_payload = (
-				random,
-				frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
-				frame_system::Pallet::<T>::block_number(),
-			);
+		/// Buy a kitty that is listed for sale.
+		///
+		/// `max_price` is the most the buyer is willing to pay. The call fails if the asking
+		/// price is above it, so a seller raising the price before the call is included cannot
+		/// charge the buyer more than they agreed to. Only the asking price is transferred.
+		#[pallet::weight(T::WeightInfo::buy_kitty(
+			T::MaxKittiesOwned::get(),
+			T::MaxOffersPerKitty::get()
+		))]
+		#[transactional]
+		pub fn buy_kitty(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let buyer = ensure_signed(origin)?;
 
-			let encoded_payload = unique_payload.encode();
-			let hash = frame_support::Hashable::blake2_128(&encoded_payload);
+			// Check and perform the purchase
+			Self::do_buy_kitty(kitty_id, buyer, max_price)?;
 
-			if hash[0] % 2 == 0 {
-				(hash, Gender::Male)
-			} else {
-				(hash, Gender::Female)
-			}
+			Ok(())
 		}
 
-		// mint a kitty
-		pub fn mint(
-			owner: &T::AccountId,
-			dna: [u8; 16],
-			gender: Gender,
-		) -> Result<[u8; 16], DispatchError> {
-			// Create a new object
-			let kitty = Kitty::<T> { dna, price: None, gender, owner: owner.clone() };
+		/// Breed a new kitty from two kitties owned by the caller.
+		///
+		/// `mom` has to be female and `dad` male. The child's DNA is a random crossover of the
+		/// parents' genes and both parents have to rest for `BreedingCooldown` blocks
+		/// afterwards. The caller pays the `MintFee`.
+		#[pallet::weight(T::WeightInfo::breed_kitty())]
+		#[transactional]
+		pub fn breed_kitty(origin: OriginFor<T>, mom: [u8; 16], dad: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let sender = ensure_signed(origin)?;
+			Self::charge_mint(&sender)?;
 
-			// Check if the kitty does not already exist in our storage map
-			ensure!(!Kitties::<T>::contains_key(&kitty.dna), Error::<T>::DuplicateKitty);
+			// Both parents must exist and belong to the caller
+			let mom_kitty = Kitties::<T>::get(&mom).ok_or(Error::<T>::NoKitty)?;
+			let dad_kitty = Kitties::<T>::get(&dad).ok_or(Error::<T>::NoKitty)?;
+			ensure!(mom_kitty.owner == sender && dad_kitty.owner == sender, Error::<T>::NotOwner);
+			ensure!(
+				mom_kitty.gender == Gender::Female && dad_kitty.gender == Gender::Male,
+				Error::<T>::GenderMismatch
+			);
 
-			// Performs this operation first as it may fail
-			let count = CountForKitties::<T>::get();
-			let new_count = count.checked_add(1).ok_or(Error::<T>::Overflow)?;
+			// Neither parent may still be resting from a previous breeding
+			let now = frame_system::Pallet::<T>::block_number();
+			for parent in [&mom, &dad] {
+				if let Some(ready_at) = BreedingCooldowns::<T>::get(parent) {
+					ensure!(now >= ready_at, Error::<T>::BreedingCooldown);
+				}
+			}
 
-			// Append kitty to KittiesOwned
-			KittiesOwned::<T>::try_append(&owner, kitty.dna)
-				.map_err(|_| Error::<T>::TooManyOwned)?;
+			let generation = mom_kitty
+				.generation
+				.max(dad_kitty.generation)
+				.checked_add(1)
+				.ok_or(Error::<T>::Overflow)?;
+			let (dna, gender) = Self::breed_dna(&mom, &dad);
 
-			// Write new kitty to storage
-			Kitties::<T>::insert(kitty.dna, kitty);
-			CountForKitties::<T>::put(new_count);
+			// Write the child to storage through the regular minting path
+			let kitty = Kitty::<T> {
+				dna,
+				price: None,
+				gender,
+				owner: sender.clone(),
+				parents: Some((mom, dad)),
+				generation,
+				creator: sender.clone(),
+				royalty: Permill::zero(),
+				lock: None,
+			};
+			Self::do_mint(kitty)?;
 
-			// Deposit our "Created" event.
-			Self::deposit_event(Event::Created { kitty: dna, owner: owner.clone() });
+			// Send both parents to rest
+			let ready_at = now.saturating_add(T::BreedingCooldown::get());
+			BreedingCooldowns::<T>::insert(&mom, ready_at);
+			BreedingCooldowns::<T>::insert(&dad, ready_at);
 
-			// Returns the DNA of the new kitty if this succeeds
-			Ok(dna)
-		}
+			Self::deposit_event(Event::Bred { kitty: dna, mom, dad, owner: sender });
 
-		// transfer kitty
-		pub fn do_transfer(kitty_id: [u8; 16], to: T::AccountId) -> DispatchResult {
-			// Get the kitty
-			let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
-			let from = kitty.owner;
+			Ok(())
+		}
 
-			ensure!(from != to, Error::<T>::TransferToSelf);
-			let mut from_owned = KittiesOwned::<T>::get(&from);
+		/// Put a kitty up for an english auction.
+		///
+		/// Bids of at least `reserve_price` are accepted until the block before `end`. The
+		/// auction is settled automatically at the start of block `end`. The kitty is taken off
+		/// fixed price sale while the auction runs.
+		#[pallet::weight(T::WeightInfo::start_auction())]
+		pub fn start_auction(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			reserve_price: BalanceOf<T>,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let seller = ensure_signed(origin)?;
 
-			// Remove kitty from list of owned kitties.
-			if let Some(ind) = from_owned.iter().position(|&id| id == kitty_id) {
-				from_owned.swap_remove(ind);
-			} else {
-				return Err(Error::<T>::NoKitty.into())
-			}
+			// Ensure the kitty exists, is called by the kitty owner and is not auctioned yet
+			let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == seller, Error::<T>::NotOwner);
+			ensure!(!Self::is_auctioned(&kitty_id), Error::<T>::InAuction);
+			// The auction could not hand the kitty over at its end
+			ensure!(!Rentals::<T>::contains_key(&kitty_id), Error::<T>::Rented);
+			ensure!(!Self::is_locked(&kitty), Error::<T>::Locked);
 
-			// Add kitty to the list of owned kitties.
-			let mut to_owned = KittiesOwned::<T>::get(&to);
-			to_owned.try_push(kitty_id).map_err(|()| Error::<T>::TooManyOwned)?;
+			// Schedule the settlement
+			Self::schedule_auction_end(kitty_id, end)?;
 
-			// Transfer succeeded, update the kitty owner and reset the price to `None`.
-			kitty.owner = to.clone();
+			Self::unschedule_listing_expiry(&kitty_id);
 			kitty.price = None;
-
-			// Write updates to storage
 			Kitties::<T>::insert(&kitty_id, kitty);
-			KittiesOwned::<T>::insert(&to, to_owned);
-			KittiesOwned::<T>::insert(&from, from_owned);
+			ForSale::<T>::remove(&kitty_id);
+			Auctions::<T>::insert(
+				&kitty_id,
+				Auction { seller: seller.clone(), reserve_price, end, best_bid: None },
+			);
 
-			Self::deposit_event(Event::Transferred { from, to, kitty: kitty_id });
+			Self::deposit_event(Event::AuctionStarted {
+				kitty: kitty_id,
+				seller,
+				reserve_price,
+				end,
+			});
 
 			Ok(())
 		}
 
-		// A helper function for purchasing a kitty
-		pub fn do_buy_kitty(
+		/// Bid on an auctioned kitty.
+		///
+		/// The bid has to reach the reserve price and beat the current best bid. The bid amount
+		/// is reserved on the bidder's account and released again once they are outbid.
+		#[pallet::weight(T::WeightInfo::bid())]
+		#[transactional]
+		pub fn bid(
+			origin: OriginFor<T>,
 			kitty_id: [u8; 16],
-			to: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let bidder = ensure_signed(origin)?;
+
+			let mut auction = Auctions::<T>::get(&kitty_id).ok_or(Error::<T>::NotInAuction)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < auction.end,
+				Error::<T>::AuctionEnded
+			);
+			ensure!(auction.seller != bidder, Error::<T>::TransferToSelf);
+			ensure!(amount >= auction.reserve_price, Error::<T>::BidPriceTooLow);
+
+			// The winner must be able to receive the kitty
+			Self::ensure_can_own(&bidder)?;
+
+			// Hold the new bid and release the one it beats
+			match &auction.best_bid {
+				Some((best_bidder, best_amount)) => {
+					ensure!(amount > *best_amount, Error::<T>::BidPriceTooLow);
+					if *best_bidder == bidder {
+						T::Currency::reserve(&bidder, amount.saturating_sub(*best_amount))?;
+					} else {
+						T::Currency::reserve(&bidder, amount)?;
+						T::Currency::unreserve(best_bidder, *best_amount);
+					}
+				},
+				None => T::Currency::reserve(&bidder, amount)?,
+			}
+
+			auction.best_bid = Some((bidder.clone(), amount));
+			Auctions::<T>::insert(&kitty_id, auction);
+
+			Self::deposit_event(Event::BidPlaced { kitty: kitty_id, bidder, amount });
+
+			Ok(())
+		}
+
+		/// Cancel an english auction that has not received any bids yet, or a dutch auction.
+		#[pallet::weight(T::WeightInfo::cancel_auction(T::MaxAuctionsPerBlock::get()))]
+		pub fn cancel_auction(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let sender = ensure_signed(origin)?;
+
+			let end = if let Some(auction) = Auctions::<T>::get(&kitty_id) {
+				ensure!(auction.seller == sender, Error::<T>::NotOwner);
+				ensure!(auction.best_bid.is_none(), Error::<T>::AuctionHasBids);
+				Auctions::<T>::remove(&kitty_id);
+				auction.end
+			} else {
+				let auction = DutchAuctions::<T>::get(&kitty_id).ok_or(Error::<T>::NotInAuction)?;
+				ensure!(auction.seller == sender, Error::<T>::NotOwner);
+				DutchAuctions::<T>::remove(&kitty_id);
+				ForSale::<T>::remove(&kitty_id);
+				auction.end
+			};
+			Self::unschedule_auction_end(kitty_id, end);
+
+			Self::deposit_event(Event::AuctionCancelled { kitty: kitty_id });
+
+			Ok(())
+		}
+
+		/// List a kitty in a dutch auction.
+		///
+		/// The asking price starts at `start_price` and falls linearly to `floor_price` at block
+		/// `end`, where the listing expires. The first buyer to pay the current price through
+		/// `buy_kitty` gets the kitty.
+		#[pallet::weight(T::WeightInfo::start_dutch_auction())]
+		pub fn start_dutch_auction(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			start_price: BalanceOf<T>,
+			floor_price: BalanceOf<T>,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let seller = ensure_signed(origin)?;
+
+			// Ensure the kitty exists, is called by the kitty owner and is not auctioned yet
+			let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == seller, Error::<T>::NotOwner);
+			ensure!(!Self::is_auctioned(&kitty_id), Error::<T>::InAuction);
+			// A rented kitty cannot be sold, so every purchase would fail
+			ensure!(!Rentals::<T>::contains_key(&kitty_id), Error::<T>::Rented);
+			ensure!(!Self::is_locked(&kitty), Error::<T>::Locked);
+			ensure!(floor_price <= start_price, Error::<T>::InvalidPriceRange);
+
+			// Schedule the expiry
+			Self::schedule_auction_end(kitty_id, end)?;
+
+			Self::unschedule_listing_expiry(&kitty_id);
+			kitty.price = None;
+			Kitties::<T>::insert(&kitty_id, kitty);
+			ForSale::<T>::insert(&kitty_id, ());
+			DutchAuctions::<T>::insert(
+				&kitty_id,
+				DutchAuction {
+					seller: seller.clone(),
+					start_price,
+					floor_price,
+					start: frame_system::Pallet::<T>::block_number(),
+					end,
+				},
+			);
+
+			Self::deposit_event(Event::DutchAuctionStarted {
+				kitty: kitty_id,
+				seller,
+				start_price,
+				floor_price,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Offer to buy a kitty, whether or not it is for sale.
+		///
+		/// The offered amount is reserved on the caller's account until the offer is withdrawn,
+		/// accepted, rejected or the kitty changes owner. A new offer replaces the caller's
+		/// previous one. Once the kitty has `MaxOffersPerKitty` offers, a new bidder takes the
+		/// place of an expired offer, or else of the lowest one if they offer more.
+		#[pallet::weight(T::WeightInfo::make_offer(T::MaxOffersPerKitty::get()))]
+		#[transactional]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			amount: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let bidder = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner != bidder, Error::<T>::TransferToSelf);
+			ensure!(amount >= T::Currency::minimum_balance(), Error::<T>::OfferTooLow);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidOfferExpiry
+			);
+
+			match Offers::<T>::get(&kitty_id, &bidder) {
+				// Release the offer being replaced
+				Some(previous) => {
+					T::Currency::unreserve(&bidder, previous.amount);
+				},
+				None => {
+					if OfferCount::<T>::get(&kitty_id) >= T::MaxOffersPerKitty::get() {
+						Self::drop_weakest_offer(&kitty_id, amount)?;
+					}
+					OfferCount::<T>::mutate(&kitty_id, |count| *count += 1);
+				},
+			}
+
+			T::Currency::reserve(&bidder, amount)?;
+			Offers::<T>::insert(&kitty_id, &bidder, Offer { amount, expiry });
+
+			Self::deposit_event(Event::OfferMade { kitty: kitty_id, bidder, amount, expiry });
+
+			Ok(())
+		}
+
+		/// Withdraw the caller's offer on a kitty and release the reserved funds.
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		pub fn withdraw_offer(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let bidder = ensure_signed(origin)?;
+
+			let offer = Self::take_offer(&kitty_id, &bidder).ok_or(Error::<T>::NoOffer)?;
+			T::Currency::unreserve(&bidder, offer.amount);
+
+			Self::deposit_event(Event::OfferWithdrawn { kitty: kitty_id, bidder });
+
+			Ok(())
+		}
+
+		/// Reject an open offer on one of the caller's kitties and release the reserved funds.
+		#[pallet::weight(T::WeightInfo::reject_offer())]
+		pub fn reject_offer(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			bidder: T::AccountId,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == owner, Error::<T>::NotOwner);
+
+			let offer = Self::take_offer(&kitty_id, &bidder).ok_or(Error::<T>::NoOffer)?;
+			T::Currency::unreserve(&bidder, offer.amount);
+
+			Self::deposit_event(Event::OfferRejected { kitty: kitty_id, bidder });
+
+			Ok(())
+		}
+
+		/// Accept an open offer on one of the caller's kitties.
+		///
+		/// The kitty goes to the bidder and the seller is paid out of the reserved offer. All
+		/// other offers on the kitty are released.
+		#[pallet::weight(T::WeightInfo::accept_offer(T::MaxOffersPerKitty::get()))]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			bidder: T::AccountId,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let seller = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == seller, Error::<T>::NotOwner);
+			let offer = Offers::<T>::get(&kitty_id, &bidder).ok_or(Error::<T>::NoOffer)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < offer.expiry,
+				Error::<T>::OfferExpired
+			);
+
+			// Take the accepted offer out first so the transfer does not release it
+			Self::take_offer(&kitty_id, &bidder);
+			Self::do_transfer(kitty_id, bidder.clone())?;
+
+			// Pay the seller straight out of the offer's reserve
+			Self::pay_sale(&kitty_id, &bidder, &seller, offer.amount, true)?;
+
+			Self::deposit_event(Event::OfferAccepted {
+				kitty: kitty_id,
+				seller,
+				buyer: bidder,
+				price: offer.amount,
+			});
+
+			Ok(())
+		}
+
+		/// Set the royalty paid to the creator on every future sale of a kitty.
+		///
+		/// Only the creator can set it, and only while they still own the kitty, so buyers are
+		/// never charged a royalty they did not know about when they bought it.
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			royalty: Permill,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let sender = ensure_signed(origin)?;
+
+			let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.creator == sender, Error::<T>::NotCreator);
+			ensure!(kitty.owner == sender, Error::<T>::NotOwner);
+			ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+
+			kitty.royalty = royalty;
+			Kitties::<T>::insert(&kitty_id, kitty);
+
+			Self::deposit_event(Event::RoyaltySet { kitty: kitty_id, royalty });
+
+			Ok(())
+		}
+
+		/// Approve an account to transfer a kitty of the caller, or revoke the approval with
+		/// `None`.
+		///
+		/// A kitty has at most one approved account, which is cleared when the kitty changes
+		/// hands.
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			approved: Option<T::AccountId>,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == owner, Error::<T>::NotOwner);
+
+			match approved {
+				Some(ref account) => {
+					ensure!(*account != owner, Error::<T>::ApproveToSelf);
+					Approvals::<T>::insert(&kitty_id, account);
+				},
+				None => Approvals::<T>::remove(&kitty_id),
+			}
+
+			Self::deposit_event(Event::Approval { kitty: kitty_id, owner, approved });
+
+			Ok(())
+		}
+
+		/// Approve or revoke an operator that can transfer every kitty of the caller, including
+		/// kitties received later.
+		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+			ensure!(operator != owner, Error::<T>::ApproveToSelf);
+
+			if approved {
+				OperatorApprovals::<T>::insert(&owner, &operator, ());
+			} else {
+				OperatorApprovals::<T>::remove(&owner, &operator);
+			}
+
+			Self::deposit_event(Event::ApprovalForAll { owner, operator, approved });
+
+			Ok(())
+		}
+
+		/// Destroy a kitty owned by the caller.
+		///
+		/// Any listing of the kitty is closed and open offers on it are released. A kitty in an
+		/// english auction that already has bids or that is rented out cannot be burned.
+		#[pallet::weight(T::WeightInfo::burn_kitty(T::MaxOffersPerKitty::get()))]
+		pub fn burn_kitty(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let sender = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == sender, Error::<T>::NotOwner);
+			if let Some(auction) = Auctions::<T>::get(&kitty_id) {
+				ensure!(auction.best_bid.is_none(), Error::<T>::AuctionHasBids);
+			}
+			ensure!(!Rentals::<T>::contains_key(&kitty_id), Error::<T>::Rented);
+
+			Self::do_burn(kitty_id)
+		}
+
+		/// Destroy any kitty, for moderation.
+		///
+		/// The best bid of an english auction on the kitty is returned to its bidder and a
+		/// running rental ends without a refund.
+		#[pallet::weight(T::WeightInfo::force_burn(T::MaxOffersPerKitty::get()))]
+		pub fn force_burn(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::do_burn(kitty_id)
+		}
+
+		/// Offer a kitty of the caller for rent at `fee_per_block` for up to `max_duration`
+		/// blocks, replacing any previous terms.
+		///
+		/// New terms only apply to later rentals, a running rental is left as it is.
+		#[pallet::weight(T::WeightInfo::offer_rental())]
+		pub fn offer_rental(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			fee_per_block: BalanceOf<T>,
+			max_duration: T::BlockNumber,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == owner, Error::<T>::NotOwner);
+			ensure!(!max_duration.is_zero(), Error::<T>::InvalidRentalDuration);
+
+			RentalOffers::<T>::insert(&kitty_id, RentalOffer { fee_per_block, max_duration });
+
+			Self::deposit_event(Event::RentalOffered {
+				kitty: kitty_id,
+				fee_per_block,
+				max_duration,
+			});
+
+			Ok(())
+		}
+
+		/// Stop offering a kitty of the caller for rent.
+		#[pallet::weight(T::WeightInfo::withdraw_rental_offer())]
+		pub fn withdraw_rental_offer(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == owner, Error::<T>::NotOwner);
+			RentalOffers::<T>::take(&kitty_id).ok_or(Error::<T>::NotForRent)?;
+
+			Self::deposit_event(Event::RentalOfferWithdrawn { kitty: kitty_id });
+
+			Ok(())
+		}
+
+		/// Rent a kitty for `duration` blocks on the terms its owner offers.
+		///
+		/// The whole fee is paid to the owner upfront and is never refunded. `max_fee` is the
+		/// most the renter is willing to pay, like the `max_price` of `buy_kitty`. The kitty
+		/// cannot be transferred, sold, auctioned or burned by its owner until the rental ends at
+		/// the start of block `now + duration`.
+		#[pallet::weight(T::WeightInfo::rent())]
+		#[transactional]
+		pub fn rent(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			duration: T::BlockNumber,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let renter = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner != renter, Error::<T>::RentToSelf);
+			let offer = RentalOffers::<T>::get(&kitty_id).ok_or(Error::<T>::NotForRent)?;
+			ensure!(!Rentals::<T>::contains_key(&kitty_id), Error::<T>::Rented);
+			ensure!(!Self::is_auctioned(&kitty_id), Error::<T>::InAuction);
+			ensure!(
+				!duration.is_zero() && duration <= offer.max_duration,
+				Error::<T>::InvalidRentalDuration
+			);
+
+			let blocks: BalanceOf<T> = duration.saturated_into::<u128>().saturated_into();
+			let fee = offer.fee_per_block.saturating_mul(blocks);
+			ensure!(fee <= max_fee, Error::<T>::BidPriceTooLow);
+
+			// Schedule the end of the rental
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			RentalsEnding::<T>::try_append(end, kitty_id)
+				.map_err(|_| Error::<T>::TooManyRentals)?;
+
+			T::Currency::transfer(&renter, &kitty.owner, fee, ExistenceRequirement::KeepAlive)?;
+			Rentals::<T>::insert(&kitty_id, Rental { renter: renter.clone(), end });
+
+			Self::deposit_event(Event::Rented {
+				kitty: kitty_id,
+				owner: kitty.owner,
+				renter,
+				fee,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Propose to trade a kitty of the caller for the `wanted` kitty of another account,
+		/// optionally paying `top_up` on top, replacing any previous proposal for the kitty.
+		///
+		/// Nothing is moved or reserved until the owner of `wanted` accepts the swap.
+		#[pallet::weight(T::WeightInfo::propose_swap())]
+		pub fn propose_swap(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			wanted: [u8; 16],
+			top_up: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let proposer = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == proposer, Error::<T>::NotOwner);
+			let wanted_kitty = Kitties::<T>::get(&wanted).ok_or(Error::<T>::NoKitty)?;
+			ensure!(wanted_kitty.owner != proposer, Error::<T>::SwapWithSelf);
+
+			Swaps::<T>::insert(&kitty_id, Swap { proposer: proposer.clone(), wanted, top_up });
+
+			Self::deposit_event(Event::SwapProposed { kitty: kitty_id, wanted, proposer, top_up });
+
+			Ok(())
+		}
+
+		/// Withdraw the swap proposal for a kitty of the caller.
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub fn cancel_swap(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let proposer = ensure_signed(origin)?;
+
+			let swap = Swaps::<T>::get(&kitty_id).ok_or(Error::<T>::NoSwap)?;
+			ensure!(swap.proposer == proposer, Error::<T>::NotOwner);
+			Swaps::<T>::remove(&kitty_id);
+
+			Self::deposit_event(Event::SwapCancelled { kitty: kitty_id });
+
+			Ok(())
+		}
+
+		/// Accept the swap proposed for `kitty_id`, giving the wanted kitty of the caller in
+		/// return and receiving the top-up, if any.
+		///
+		/// Both kitties are checked like a regular transfer, so neither may be auctioned, rented
+		/// or locked. Each owner gives up a kitty for the one they receive, so owners holding
+		/// `MaxKittiesOwned` kitties can swap too. If either leg or the top-up fails, nothing
+		/// changes hands.
+		#[pallet::weight(T::WeightInfo::accept_swap(T::MaxOffersPerKitty::get()))]
+		#[transactional]
+		pub fn accept_swap(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let acceptor = ensure_signed(origin)?;
+
+			let swap = Swaps::<T>::take(&kitty_id).ok_or(Error::<T>::NoSwap)?;
+			let wanted_kitty = Kitties::<T>::get(&swap.wanted).ok_or(Error::<T>::NoKitty)?;
+			ensure!(wanted_kitty.owner == acceptor, Error::<T>::NotOwner);
+
+			let offered = Self::transferable(&kitty_id, &acceptor)?;
+			let wanted = Self::transferable(&swap.wanted, &swap.proposer)?;
+
+			// Take both kitties out of the owner indexes before adding either, so neither owner
+			// needs room for a kitty more than they end up with
+			Self::remove_owned(&offered.owner, &kitty_id);
+			Self::remove_owned(&acceptor, &swap.wanted);
+			Self::add_owned(&acceptor, kitty_id)?;
+			Self::add_owned(&offered.owner, swap.wanted)?;
+
+			Self::hand_over(kitty_id, offered, acceptor.clone());
+			Self::hand_over(swap.wanted, wanted, swap.proposer.clone());
+			if let Some(top_up) = swap.top_up {
+				T::Currency::transfer(
+					&swap.proposer,
+					&acceptor,
+					top_up,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			Self::deposit_event(Event::Swapped {
+				kitty: kitty_id,
+				wanted: swap.wanted,
+				proposer: swap.proposer,
+				acceptor,
+				top_up: swap.top_up,
+			});
+
+			Ok(())
+		}
+
+		/// Name a kitty of the caller.
+		///
+		/// The first name reserves the `NameDeposit` from the caller, renaming keeps the deposit
+		/// already held. The name and its deposit go with the kitty to every new owner.
+		#[pallet::weight(T::WeightInfo::set_kitty_name())]
+		pub fn set_kitty_name(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			name: Vec<u8>,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+
+			let bounded_name: BoundedVec<_, _> =
+				name.try_into().map_err(|()| Error::<T>::NameTooLong)?;
+			ensure!(
+				bounded_name.len() >= T::MinNameLength::get() as usize,
+				Error::<T>::NameTooShort
+			);
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == owner, Error::<T>::NotOwner);
+
+			let deposit = if let Some((_, deposit)) = KittyNames::<T>::get(&kitty_id) {
+				Self::deposit_event(Event::NameChanged { kitty: kitty_id });
+				deposit
+			} else {
+				let deposit = T::NameDeposit::get();
+				T::Currency::reserve(&owner, deposit)?;
+				Self::deposit_event(Event::NameSet { kitty: kitty_id });
+				deposit
+			};
+
+			KittyNames::<T>::insert(&kitty_id, (bounded_name, deposit));
+
+			Ok(())
+		}
+
+		/// Clear the name of a kitty of the caller and return the deposit.
+		#[pallet::weight(T::WeightInfo::clear_kitty_name())]
+		pub fn clear_kitty_name(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == owner, Error::<T>::NotOwner);
+			let deposit = Self::release_name(&kitty_id, &owner).ok_or(Error::<T>::Unnamed)?;
+
+			Self::deposit_event(Event::NameCleared { kitty: kitty_id, deposit });
+
+			Ok(())
+		}
+
+		/// Clear the name of any kitty, for moderation. The deposit is slashed from the owner.
+		#[pallet::weight(T::WeightInfo::force_clear_name())]
+		pub fn force_clear_name(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			let (_, deposit) = KittyNames::<T>::take(&kitty_id).ok_or(Error::<T>::Unnamed)?;
+			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&kitty.owner, deposit).0);
+
+			Self::deposit_event(Event::NameKilled { kitty: kitty_id, deposit });
+
+			Ok(())
+		}
+
+		/// Transfer several kitties in one go, each to its own recipient.
+		///
+		/// Every transfer is checked like `transfer` and emits its own event. If any of them
+		/// fails, none of the kitties move.
+		#[pallet::weight(T::WeightInfo::batch_transfer(transfers.len() as u32))]
+		#[transactional]
+		pub fn batch_transfer(
+			origin: OriginFor<T>,
+			transfers: Vec<(T::AccountId, [u8; 16])>,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let sender = ensure_signed(origin)?;
+			ensure!(transfers.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			for (to, kitty_id) in transfers {
+				let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+				ensure!(
+					Self::is_approved_or_owner(&sender, &kitty_id, &kitty.owner),
+					Error::<T>::NotOwner
+				);
+				Self::do_transfer(kitty_id, to)?;
+			}
+
+			Ok(())
+		}
+
+		/// Mint generation zero kitties with the given dna, for airdrops.
+		///
+		/// The gender of every kitty follows from its dna. No mint fee is charged and the kitties
+		/// do not count towards `MaxMintsPerBlock`, but `MaxKittySupply` and `MaxKittiesOwned`
+		/// apply. If any kitty cannot be minted, none are.
+		#[pallet::weight(T::WeightInfo::batch_mint(kitties.len() as u32))]
+		#[transactional]
+		pub fn batch_mint(
+			origin: OriginFor<T>,
+			kitties: Vec<(T::AccountId, [u8; 16])>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(kitties.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			for (owner, dna) in kitties {
+				Self::mint(&owner, dna, Self::gender_of(&dna))?;
+			}
+
+			Ok(())
+		}
+
+		/// Lock a kitty with its owner until the block `until`.
+		///
+		/// A locked kitty cannot be transferred, sold or listed, so a fixed price listing of the
+		/// kitty is withdrawn. A running lock can be extended but not shortened.
+		#[pallet::weight(T::WeightInfo::lock_kitty())]
+		pub fn lock_kitty(
+			origin: OriginFor<T>,
+			kitty_id: [u8; 16],
+			until: T::BlockNumber,
+		) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let sender = ensure_signed(origin)?;
+
+			let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(kitty.owner == sender, Error::<T>::NotOwner);
+			// A running auction, of either kind, could not sell a locked kitty
+			ensure!(!Self::is_auctioned(&kitty_id), Error::<T>::InAuction);
+			ensure!(until > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidLock);
+			match kitty.lock {
+				Some(Lock::Soulbound) => return Err(Error::<T>::Locked.into()),
+				Some(Lock::Until(current)) => ensure!(until >= current, Error::<T>::InvalidLock),
+				None => {},
+			}
+
+			kitty.lock = Some(Lock::Until(until));
+			kitty.price = None;
+			Self::unschedule_listing_expiry(&kitty_id);
+			Kitties::<T>::insert(&kitty_id, kitty);
+			ForSale::<T>::remove(&kitty_id);
+
+			Self::deposit_event(Event::Locked { kitty: kitty_id, until });
+
+			Ok(())
+		}
+
+		/// Bind a kitty to its owner for good, for soulbound rewards.
+		///
+		/// A fixed price listing of the kitty is withdrawn.
+		#[pallet::weight(T::WeightInfo::soulbind())]
+		pub fn soulbind(origin: OriginFor<T>, kitty_id: [u8; 16]) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+			ensure!(!Self::is_auctioned(&kitty_id), Error::<T>::InAuction);
+
+			kitty.lock = Some(Lock::Soulbound);
+			kitty.price = None;
+			Self::unschedule_listing_expiry(&kitty_id);
+			Kitties::<T>::insert(&kitty_id, kitty);
+			ForSale::<T>::remove(&kitty_id);
+
+			Self::deposit_event(Event::Soulbound { kitty: kitty_id });
+
+			Ok(())
+		}
+
+		/// Commit to minting a kitty whose dna mixes a secret with the hash of a future block.
+		///
+		/// `hash` is the `mint_commitment` of the caller and a secret only they know. The
+		/// `CommitDeposit` is reserved until the secret is revealed with `reveal_mint`, which can
+		/// be done from `RevealDelay` blocks on and for `RevealWindow` blocks. The dna is seeded
+		/// by the block before the first one the secret can be revealed in. The deposit is
+		/// forfeited when the commitment expires unrevealed.
+		#[pallet::weight(T::WeightInfo::commit_mint())]
+		#[transactional]
+		pub fn commit_mint(origin: OriginFor<T>, hash: [u8; 32]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+			ensure!(!MintCommits::<T>::contains_key(&owner), Error::<T>::CommitPending);
+
+			let reveal_from =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::RevealDelay::get());
+			let expires = reveal_from.saturating_add(T::RevealWindow::get());
+
+			CommitsExpiring::<T>::try_append(expires, owner.clone())
+				.map_err(|_| Error::<T>::TooManyCommits)?;
+
+			let deposit = T::CommitDeposit::get();
+			T::Currency::reserve(&owner, deposit)?;
+			MintCommits::<T>::insert(&owner, MintCommit { hash, deposit, reveal_from, expires });
+
+			Self::deposit_event(Event::MintCommitted { owner, expires });
+
+			Ok(())
+		}
+
+		/// Reveal the secret of the caller's mint commitment and create the kitty.
+		///
+		/// The dna hashes the secret with the hash of the block fixed by the commitment, so it
+		/// is the same whichever block the secret is revealed in, and nobody knows it before that
+		/// block is built. The caller can work it out from then on and get another dna by letting
+		/// the commitment expire and committing again, but forfeits the deposit every time. The
+		/// caller pays the `MintFee` and gets the deposit back.
+		#[pallet::weight(T::WeightInfo::reveal_mint(T::MaxKittiesOwned::get()))]
+		#[transactional]
+		pub fn reveal_mint(origin: OriginFor<T>, secret: [u8; 32]) -> DispatchResult {
+			// Make sure the caller is from a signed origin
+			let owner = ensure_signed(origin)?;
+
+			let commit = MintCommits::<T>::get(&owner).ok_or(Error::<T>::NoCommit)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= commit.reveal_from,
+				Error::<T>::RevealTooEarly
+			);
+			ensure!(Self::mint_commitment(&owner, &secret) == commit.hash, Error::<T>::BadReveal);
+
+			Self::charge_mint(&owner)?;
+			let seed_block = commit.reveal_from.saturating_sub(1u32.into());
+			let (dna, gender) = Self::reveal_dna(&owner, &secret, seed_block);
+			Self::mint(&owner, dna, gender)?;
+
+			MintCommits::<T>::remove(&owner);
+			CommitsExpiring::<T>::mutate(commit.expires, |expiring| {
+				expiring.retain(|account| *account != owner)
+			});
+			T::Currency::unreserve(&owner, commit.deposit);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		// generate random DNA string for kitty
+		fn gen_dna() -> ([u8; 16], Gender) {
+			let random = T::KittyRandomness::random(&b"dna"[..]).0;
+
+			let unique_payload = (
+				random,
+				frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+				frame_system::Pallet::<T>::block_number(),
+			);
+
+			let encoded_payload = unique_payload.encode();
+			let hash = frame_support::Hashable::blake2_128(&encoded_payload);
+
+			(hash, Self::gender_of(&hash))
+		}
+
+		// hash a revealed secret with the hash of `seed_block`, a block sealed after the
+		// commitment
+		fn reveal_dna(
+			owner: &T::AccountId,
+			secret: &[u8; 32],
+			seed_block: T::BlockNumber,
+		) -> ([u8; 16], Gender) {
+			let seed = frame_system::Pallet::<T>::block_hash(seed_block);
+
+			let encoded_payload = (seed, secret, owner).encode();
+			let hash = frame_support::Hashable::blake2_128(&encoded_payload);
+
+			(hash, Self::gender_of(&hash))
+		}
+
+		/// The hash to commit to for minting with `secret` from `who`.
+		pub fn mint_commitment(who: &T::AccountId, secret: &[u8; 32]) -> [u8; 32] {
+			frame_support::Hashable::blake2_256(&(who, secret).encode())
+		}
+
+		// mix the parents' DNA, taking each bit from either mom or dad at random
+		fn breed_dna(mom: &[u8; 16], dad: &[u8; 16]) -> ([u8; 16], Gender) {
+			let random = T::KittyRandomness::random(&b"breed"[..]).0;
+
+			let unique_payload = (
+				random,
+				mom,
+				dad,
+				frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+				frame_system::Pallet::<T>::block_number(),
+			);
+			let selector = frame_support::Hashable::blake2_128(&unique_payload.encode());
+
+			let mut dna = [0u8; 16];
+			for (i, gene) in dna.iter_mut().enumerate() {
+				*gene = (mom[i] & selector[i]) | (dad[i] & !selector[i]);
+			}
+
+			(dna, Self::gender_of(&dna))
+		}
+
+		// the gender is encoded in the lowest bit of the dna
+		pub(crate) fn gender_of(dna: &[u8; 16]) -> Gender {
+			if dna[0] % 2 == 0 {
+				Gender::Male
+			} else {
+				Gender::Female
+			}
+		}
+
+		// mint a kitty
+		pub fn mint(
+			owner: &T::AccountId,
+			dna: [u8; 16],
+			gender: Gender,
+		) -> Result<[u8; 16], DispatchError> {
+			// Create a new generation zero object
+			let kitty = Kitty::<T> {
+				dna,
+				price: None,
+				gender,
+				owner: owner.clone(),
+				parents: None,
+				generation: 0,
+				creator: owner.clone(),
+				royalty: Permill::zero(),
+				lock: None,
+			};
+
+			Self::do_mint(kitty)
+		}
+
+		// write a newly created kitty to storage
+		fn do_mint(kitty: Kitty<T>) -> Result<[u8; 16], DispatchError> {
+			let dna = kitty.dna;
+			let owner = kitty.owner.clone();
+
+			// Check if the kitty does not already exist in our storage map
+			ensure!(!Kitties::<T>::contains_key(&kitty.dna), Error::<T>::DuplicateKitty);
+
+			// Performs this operation first as it may fail
+			let count = CountForKitties::<T>::get();
+			let new_count = count.checked_add(1).ok_or(Error::<T>::Overflow)?;
+			ensure!(new_count <= T::MaxKittySupply::get(), Error::<T>::MaxSupplyReached);
+
+			// Add kitty to the owner's index
+			Self::add_owned(&owner, kitty.dna)?;
+
+			// Write new kitty to storage
+			Kitties::<T>::insert(kitty.dna, kitty);
+			CountForKitties::<T>::put(new_count);
+
+			// Deposit our "Created" event.
+			Self::deposit_event(Event::Created { kitty: dna, owner });
+
+			// Returns the DNA of the new kitty if this succeeds
+			Ok(dna)
+		}
+
+		// transfer kitty
+		pub fn do_transfer(kitty_id: [u8; 16], to: T::AccountId) -> DispatchResult {
+			let kitty = Self::transferable(&kitty_id, &to)?;
+
+			// Move the kitty between the owner indexes, adding first as it may fail
+			Self::add_owned(&to, kitty_id)?;
+			Self::remove_owned(&kitty.owner, &kitty_id);
+
+			Self::hand_over(kitty_id, kitty, to);
+
+			Ok(())
+		}
+
+		// the kitty, if its owner may transfer it to `to`
+		fn transferable(kitty_id: &[u8; 16], to: &T::AccountId) -> Result<Kitty<T>, DispatchError> {
+			let kitty = Kitties::<T>::get(kitty_id).ok_or(Error::<T>::NoKitty)?;
+
+			ensure!(kitty.owner != *to, Error::<T>::TransferToSelf);
+			ensure!(!Self::is_auctioned(kitty_id), Error::<T>::InAuction);
+			ensure!(!Rentals::<T>::contains_key(kitty_id), Error::<T>::Rented);
+			ensure!(!Self::is_locked(&kitty), Error::<T>::Locked);
+
+			Ok(kitty)
+		}
+
+		// give a kitty that already moved between the owner indexes to `to`
+		fn hand_over(kitty_id: [u8; 16], mut kitty: Kitty<T>, to: T::AccountId) {
+			let from = kitty.owner;
+
+			// Update the kitty owner and reset the price to `None`.
+			kitty.owner = to.clone();
+			kitty.price = None;
+
+			// Write updates to storage
+			Kitties::<T>::insert(&kitty_id, kitty);
+			Self::clear_for_new_owner(&kitty_id, &from, &to);
+
+			Self::deposit_event(Event::Transferred { from, to, kitty: kitty_id });
+		}
+
+		// A helper function for purchasing a kitty
+		pub fn do_buy_kitty(
+			kitty_id: [u8; 16],
+			to: T::AccountId,
 			bid_price: BalanceOf<T>,
 		) -> DispatchResult {
 			// Get the kitty
@@ -256,39 +1995,29 @@ pub mod pallet {
 
 			// make sure owner is not buying again. wrong move
 			ensure!(from != to, Error::<T>::TransferToSelf);
-			//get list of kitties owned by the owner of the kitty being bought
-			let mut from_owned = KittiesOwned::<T>::get(&from);
+			ensure!(!Rentals::<T>::contains_key(&kitty_id), Error::<T>::Rented);
+			ensure!(!Self::is_locked(&kitty), Error::<T>::Locked);
 
-			// Remove kitty from list of owned kitties.
-			if let Some(ind) = from_owned.iter().position(|&id| id == kitty_id) {
-				from_owned.swap_remove(ind);
-			} else {
-				return Err(Error::<T>::NoKitty.into())
-			}
+			// Only listed kitties can be bought, and never above the buyer's bid
+			let price = Self::current_price(&kitty_id).ok_or(Error::<T>::NotForSale)?;
+			ensure!(bid_price >= price, Error::<T>::BidPriceTooLow);
+			let fee = T::MarketplaceFee::get() * price;
 
-			// Add kitty to the list of owned kitties.
-			let mut to_owned = KittiesOwned::<T>::get(&to);
-			to_owned.try_push(kitty_id).map_err(|()| Error::<T>::TooManyOwned)?;
+			// The buyer must be able to receive the kitty
+			Self::ensure_can_own(&to)?;
 
-			// Mutating state here via a balance transfer, so nothing is allowed to fail after this.
-			if let Some(price) = kitty.price {
-				ensure!(bid_price >= price, Error::<T>::BidPriceTooLow);
-				// Transfer the amount from buyer to seller
+			// Mutating state here via a balance transfer. Only the fee transfer can still fail
+			// after this, which `buy_kitty` being transactional undoes along with the payment.
+			Self::pay_sale(&kitty_id, &to, &from, price, false)?;
+			// The marketplace fee comes out of the seller's proceeds. The pallet account is funded
+			// at genesis or by `FundFeeAccount`, so fees below the existential deposit are taken.
+			if !fee.is_zero() {
 				T::Currency::transfer(
-					&to,
 					&from,
-					price,
-					frame_support::traits::ExistenceRequirement::KeepAlive,
+					&Self::account_id(),
+					fee,
+					ExistenceRequirement::AllowDeath,
 				)?;
-				// Deposit sold event
-				Self::deposit_event(Event::Sold {
-					seller: from.clone(),
-					buyer: to.clone(),
-					kitty: kitty_id,
-					price,
-				});
-			} else {
-				return Err(Error::<T>::NotForSale.into())
 			}
 
 			// Transfer succeeded, update the kitty owner and reset the price to `None`.
@@ -296,12 +2025,748 @@ pub mod pallet {
 			kitty.price = None;
 
 			// Write updates to storage
+			Self::add_owned(&to, kitty_id)?;
+			Self::remove_owned(&from, &kitty_id);
 			Kitties::<T>::insert(&kitty_id, kitty);
-			KittiesOwned::<T>::insert(&to, to_owned);
-			KittiesOwned::<T>::insert(&from, from_owned);
 
-			Self::deposit_event(Event::Transferred { from, to, kitty: kitty_id });
+			// A dutch auction ends with its first sale
+			if let Some(auction) = DutchAuctions::<T>::take(&kitty_id) {
+				Self::unschedule_auction_end(kitty_id, auction.end);
+			}
+			Self::clear_for_new_owner(&kitty_id, &from, &to);
+
+			Self::deposit_event(Event::Transferred {
+				from: from.clone(),
+				to: to.clone(),
+				kitty: kitty_id,
+			});
+			Self::deposit_event(Event::Sold {
+				seller: from,
+				buyer: to,
+				kitty: kitty_id,
+				price,
+				fee,
+			});
+
+			Ok(())
+		}
+
+		// Remove a kitty with all its listings, offers, rentals and swap proposals, returning the
+		// deposit of its name
+		pub fn do_burn(kitty_id: [u8; 16]) -> DispatchResult {
+			let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+
+			// Close any auction, handing a held bid back to its bidder
+			if let Some(auction) = Auctions::<T>::take(&kitty_id) {
+				if let Some((bidder, amount)) = auction.best_bid {
+					T::Currency::unreserve(&bidder, amount);
+				}
+				Self::unschedule_auction_end(kitty_id, auction.end);
+			}
+			if let Some(auction) = DutchAuctions::<T>::take(&kitty_id) {
+				Self::unschedule_auction_end(kitty_id, auction.end);
+			}
+			if let Some(rental) = Rentals::<T>::take(&kitty_id) {
+				RentalsEnding::<T>::mutate(rental.end, |ending| {
+					ending.retain(|id| *id != kitty_id)
+				});
+			}
+			Self::clear_trades(&kitty_id);
+			SaleHistory::<T>::remove(&kitty_id);
+			Self::release_name(&kitty_id, &kitty.owner);
+
+			Self::remove_owned(&kitty.owner, &kitty_id);
+			Kitties::<T>::remove(&kitty_id);
+			BreedingCooldowns::<T>::remove(&kitty_id);
+			CountForKitties::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::Burned { kitty: kitty_id, owner: kitty.owner });
+
+			Ok(())
+		}
+
+		// move the price of a sold kitty from the buyer, or the buyer's reserve, to the seller,
+		// who then passes the creator's royalty on
+		fn pay_sale(
+			kitty_id: &[u8; 16],
+			buyer: &T::AccountId,
+			seller: &T::AccountId,
+			price: BalanceOf<T>,
+			from_reserve: bool,
+		) -> DispatchResult {
+			// Paying the seller is the only step that can fail
+			if from_reserve {
+				let missing =
+					T::Currency::repatriate_reserved(buyer, seller, price, BalanceStatus::Free)?;
+				// Part of the reserve was slashed or released elsewhere, the seller would be
+				// short of the price
+				ensure!(missing.is_zero(), Error::<T>::InsufficientReserve);
+			} else {
+				T::Currency::transfer(buyer, seller, price, ExistenceRequirement::KeepAlive)?;
+			}
+			Self::record_sale(kitty_id, price);
+
+			let kitty = match Kitties::<T>::get(kitty_id) {
+				Some(kitty) if kitty.creator != *seller => kitty,
+				_ => return Ok(()),
+			};
+			let royalty = kitty.royalty * price;
+			if royalty.is_zero() {
+				return Ok(())
+			}
+
+			// The seller just received the full price, so this only fails if the royalty is too
+			// small to recreate a reaped creator account, in which case the seller keeps it
+			if T::Currency::transfer(
+				seller,
+				&kitty.creator,
+				royalty,
+				ExistenceRequirement::AllowDeath,
+			)
+			.is_ok()
+			{
+				Self::deposit_event(Event::RoyaltyPaid {
+					kitty: *kitty_id,
+					creator: kitty.creator,
+					amount: royalty,
+				});
+			}
+
+			Ok(())
+		}
+
+		// add a sale to the history of the kitty and the statistics of its fur colour
+		fn record_sale(kitty_id: &[u8; 16], price: BalanceOf<T>) {
+			let now = frame_system::Pallet::<T>::block_number();
+			SaleHistory::<T>::mutate(kitty_id, |history| {
+				if history.is_full() {
+					history.remove(0);
+				}
+				// Cannot fail with the oldest sale dropped, unless `MaxSaleHistory` is zero
+				let _ = history.try_push(Sale { price, block: now });
+			});
+			ClassStats::<T>::mutate(genome::decode(kitty_id).fur, |stats| {
+				let mut next = stats.take().unwrap_or(MarketStats {
+					period_start: now,
+					volume: Zero::zero(),
+					previous_volume: Zero::zero(),
+					sales: 0,
+					last_price: price,
+					last_sale: now,
+				});
+				Self::roll_stats(&mut next, now);
+				next.volume = next.volume.saturating_add(price);
+				next.sales = next.sales.saturating_add(1);
+				next.last_price = price;
+				next.last_sale = now;
+				*stats = Some(next);
+			});
+		}
+
+		// move the volume periods of the statistics on to the period holding `now`
+		fn roll_stats(stats: &mut MarketStats<T>, now: T::BlockNumber) {
+			let period = T::StatsPeriod::get().max(1u32.into());
+			let elapsed = now.saturating_sub(stats.period_start);
+			if elapsed < period {
+				return
+			}
+			stats.previous_volume = if elapsed < period.saturating_mul(2u32.into()) {
+				stats.volume
+			} else {
+				Zero::zero()
+			};
+			stats.volume = Zero::zero();
+			stats.period_start = now - elapsed % period;
+		}
+
+		// tidy up after a kitty changed hands. Its listing, offers, approval, rental terms and
+		// swap proposal were made by and to the previous owner, its name goes with the kitty
+		fn clear_for_new_owner(kitty_id: &[u8; 16], from: &T::AccountId, to: &T::AccountId) {
+			Self::clear_trades(kitty_id);
+			Self::hand_over_name(kitty_id, from, to);
+		}
+
+		// withdraw the listing, offers, approval, rental terms and swap proposal of a kitty
+		fn clear_trades(kitty_id: &[u8; 16]) {
+			Self::unschedule_listing_expiry(kitty_id);
+			ForSale::<T>::remove(kitty_id);
+			Self::clear_offers(kitty_id);
+			Approvals::<T>::remove(kitty_id);
+			RentalOffers::<T>::remove(kitty_id);
+			Swaps::<T>::remove(kitty_id);
+		}
+
+		// move the deposit of a kitty's name to its new owner along with the kitty, or give it
+		// back to the previous owner and drop the name if the new owner cannot hold it
+		fn hand_over_name(kitty_id: &[u8; 16], from: &T::AccountId, to: &T::AccountId) {
+			let (name, deposit) = match KittyNames::<T>::get(kitty_id) {
+				Some(named) => named,
+				None => return,
+			};
+
+			match T::Currency::repatriate_reserved(from, to, deposit, BalanceStatus::Reserved) {
+				Ok(missing) if missing.is_zero() => (),
+				// Only the part that was actually reserved moved over
+				Ok(missing) =>
+					KittyNames::<T>::insert(kitty_id, (name, deposit.saturating_sub(missing))),
+				Err(_) => {
+					KittyNames::<T>::remove(kitty_id);
+					T::Currency::unreserve(from, deposit);
+					Self::deposit_event(Event::NameCleared { kitty: *kitty_id, deposit });
+				},
+			}
+		}
+
+		// drop the name of a kitty and return its deposit to `owner`
+		fn release_name(kitty_id: &[u8; 16], owner: &T::AccountId) -> Option<BalanceOf<T>> {
+			let (_, deposit) = KittyNames::<T>::take(kitty_id)?;
+			T::Currency::unreserve(owner, deposit);
+			Some(deposit)
+		}
+
+		// count a kitty created or bred by a call against the block's limit and charge its fee
+		fn charge_mint(minter: &T::AccountId) -> DispatchResult {
+			let minted = MintedInBlock::<T>::get();
+			ensure!(minted < T::MaxMintsPerBlock::get(), Error::<T>::MintLimitReached);
+
+			let fee = T::MintFee::get();
+			if !fee.is_zero() {
+				T::Currency::transfer(
+					minter,
+					&Self::account_id(),
+					fee,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+			MintedInBlock::<T>::put(minted + 1);
+
+			Ok(())
+		}
+
+		// fail with `TooManyOwned` if `who` cannot receive another kitty
+		fn ensure_can_own(who: &T::AccountId) -> DispatchResult {
+			ensure!(
+				OwnedCount::<T>::get(who) < T::MaxKittiesOwned::get(),
+				Error::<T>::TooManyOwned
+			);
+			Ok(())
+		}
 
+		// add a kitty to the index of `owner`, bounded by `MaxKittiesOwned`
+		fn add_owned(owner: &T::AccountId, kitty_id: [u8; 16]) -> DispatchResult {
+			Self::ensure_can_own(owner)?;
+			OwnedCount::<T>::mutate(owner, |count| *count += 1);
+			OwnedKitties::<T>::insert(owner, kitty_id, ());
+			Ok(())
+		}
+
+		// remove a kitty from the index of `owner`
+		fn remove_owned(owner: &T::AccountId, kitty_id: &[u8; 16]) {
+			OwnedKitties::<T>::remove(owner, kitty_id);
+			OwnedCount::<T>::mutate_exists(owner, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0)
+			});
+		}
+
+		// remove an offer from storage, keeping the per kitty count in sync
+		fn take_offer(kitty_id: &[u8; 16], bidder: &T::AccountId) -> Option<Offer<T>> {
+			let offer = Offers::<T>::take(kitty_id, bidder)?;
+			OfferCount::<T>::mutate_exists(kitty_id, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0)
+			});
+			Some(offer)
+		}
+
+		// make room for an offer of `amount` by dropping an expired offer on the kitty, or else
+		// the lowest one if `amount` beats it
+		fn drop_weakest_offer(kitty_id: &[u8; 16], amount: BalanceOf<T>) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			let (bidder, offer) = Offers::<T>::iter_prefix(kitty_id)
+				.min_by_key(|(_, offer)| (offer.expiry > now, offer.amount))
+				.ok_or(Error::<T>::TooManyOffers)?;
+			ensure!(offer.expiry <= now || offer.amount < amount, Error::<T>::TooManyOffers);
+
+			Self::take_offer(kitty_id, &bidder);
+			T::Currency::unreserve(&bidder, offer.amount);
+			Self::deposit_event(Event::OfferDropped { kitty: *kitty_id, bidder });
+
+			Ok(())
+		}
+
+		// drop every open offer on a kitty and release the reserved funds
+		fn clear_offers(kitty_id: &[u8; 16]) {
+			for (bidder, offer) in Offers::<T>::drain_prefix(kitty_id) {
+				T::Currency::unreserve(&bidder, offer.amount);
+			}
+			OfferCount::<T>::remove(kitty_id);
+		}
+
+		/// Whether `account` may transfer a kitty held by `owner`, as the owner itself, the
+		/// account approved for the kitty or an operator of the owner.
+		pub fn is_approved_or_owner(
+			account: &T::AccountId,
+			kitty_id: &[u8; 16],
+			owner: &T::AccountId,
+		) -> bool {
+			account == owner ||
+				Approvals::<T>::get(kitty_id).as_ref() == Some(account) ||
+				OperatorApprovals::<T>::contains_key(owner, account)
+		}
+
+		/// A stored kitty.
+		pub fn kitty(kitty_id: &[u8; 16]) -> Option<Kitty<T>> {
+			Kitties::<T>::get(kitty_id)
+		}
+
+		/// The number of kitties in existence.
+		pub fn kitty_count() -> u64 {
+			CountForKitties::<T>::get()
+		}
+
+		/// The ids of the kitties held by `owner`.
+		pub fn kitties_of(owner: &T::AccountId) -> Vec<[u8; 16]> {
+			OwnedKitties::<T>::iter_key_prefix(owner).collect()
+		}
+
+		/// Up to `limit` kitties that can be bought in the current block and their price, in
+		/// storage order starting after the kitty `start`.
+		///
+		/// Only listed kitties are looked at. Those that are rented out, or whose listing
+		/// expired but was not withdrawn yet, are skipped.
+		pub fn kitties_for_sale(
+			start: Option<[u8; 16]>,
+			limit: u32,
+		) -> Vec<([u8; 16], BalanceOf<T>)> {
+			let listed = match start {
+				Some(start) => ForSale::<T>::iter_keys_from(ForSale::<T>::hashed_key_for(start)),
+				None => ForSale::<T>::iter_keys(),
+			};
+			listed
+				.filter_map(|kitty_id| Some((kitty_id, Self::current_price(&kitty_id)?)))
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// The account approved to transfer a kitty, if any.
+		pub fn approved(kitty_id: &[u8; 16]) -> Option<T::AccountId> {
+			Approvals::<T>::get(kitty_id)
+		}
+
+		/// Whether `operator` may transfer every kitty of `owner`.
+		pub fn is_approved_for_all(owner: &T::AccountId, operator: &T::AccountId) -> bool {
+			OperatorApprovals::<T>::contains_key(owner, operator)
+		}
+
+		/// The name of a kitty, if it has one.
+		pub fn kitty_name(kitty_id: &[u8; 16]) -> Option<Vec<u8>> {
+			KittyNames::<T>::get(kitty_id).map(|(name, _)| name.into_inner())
+		}
+
+		/// The latest sales of a kitty, oldest first.
+		pub fn sale_history(kitty_id: &[u8; 16]) -> Vec<Sale<T>> {
+			SaleHistory::<T>::get(kitty_id).into_inner()
+		}
+
+		/// The market statistics of the kitties of a fur colour as of the current block, if any
+		/// was ever sold.
+		pub fn market_stats(fur: FurColour) -> Option<MarketStats<T>> {
+			let mut stats = ClassStats::<T>::get(fur)?;
+			Self::roll_stats(&mut stats, frame_system::Pallet::<T>::block_number());
+			Some(stats)
+		}
+
+		/// The volume of sales in the last `StatsPeriod` blocks of statistics returned by
+		/// `market_stats`, counting the previous period in proportion to how much of it is still
+		/// inside the window.
+		pub fn rolling_volume(stats: &MarketStats<T>) -> BalanceOf<T> {
+			let period = T::StatsPeriod::get().max(1u32.into());
+			let now = frame_system::Pallet::<T>::block_number();
+			let elapsed = now.saturating_sub(stats.period_start).min(period);
+			let left = Perbill::from_rational(period - elapsed, period);
+			stats.volume.saturating_add(left * stats.previous_volume)
+		}
+
+		/// The lock keeping a kitty with its owner, if one is in force.
+		pub fn lock_of(kitty_id: &[u8; 16]) -> Option<Lock<T::BlockNumber>> {
+			Kitties::<T>::get(kitty_id).filter(Self::is_locked)?.lock
+		}
+
+		/// The account holding the usage rights of a rented kitty, if it is rented out.
+		pub fn renter_of(kitty_id: &[u8; 16]) -> Option<T::AccountId> {
+			Rentals::<T>::get(kitty_id).map(|rental| rental.renter)
+		}
+
+		// whether a lock in force keeps the kitty with its owner
+		pub(crate) fn is_locked(kitty: &Kitty<T>) -> bool {
+			match kitty.lock {
+				Some(Lock::Soulbound) => true,
+				Some(Lock::Until(until)) => frame_system::Pallet::<T>::block_number() < until,
+				None => false,
+			}
+		}
+
+		// whether the kitty is in an english or dutch auction
+		pub(crate) fn is_auctioned(kitty_id: &[u8; 16]) -> bool {
+			Auctions::<T>::contains_key(kitty_id) || DutchAuctions::<T>::contains_key(kitty_id)
+		}
+
+		// check an auction end block and queue the kitty for closing in it
+		fn schedule_auction_end(kitty_id: [u8; 16], end: T::BlockNumber) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				end > now && end <= now.saturating_add(T::MaxAuctionDuration::get()),
+				Error::<T>::InvalidAuctionEnd
+			);
+
+			AuctionsEnding::<T>::try_append(end, kitty_id)
+				.map_err(|_| Error::<T>::TooManyAuctions)?;
+
+			Ok(())
+		}
+
+		// drop a kitty from the closing queue of its auction's end block
+		fn unschedule_auction_end(kitty_id: [u8; 16], end: T::BlockNumber) {
+			AuctionsEnding::<T>::mutate(end, |ending| ending.retain(|id| *id != kitty_id));
+		}
+
+		// drop the expiry of a kitty's fixed price listing, if it has one
+		fn unschedule_listing_expiry(kitty_id: &[u8; 16]) {
+			if let Some(expiry) = ListingExpiry::<T>::take(kitty_id) {
+				ListingsExpiring::<T>::mutate(expiry, |expiring| {
+					expiring.retain(|id| id != kitty_id)
+				});
+			}
+		}
+
+		// withdraw the listings expired by block `n`, oldest first, up to `MaxListingExpiries`
+		// of them from as many expiry blocks. Leftovers are withdrawn in the next blocks.
+		// Returns the number of expiry blocks looked at and of listings withdrawn
+		fn expire_listings(n: T::BlockNumber) -> (u32, u32) {
+			let max = T::MaxListingExpiries::get();
+			let mut block = ExpiryCursor::<T>::get().unwrap_or(n);
+			let (mut blocks, mut expired) = (0, 0);
+
+			while block <= n && blocks < max && expired < max {
+				blocks += 1;
+				let mut expiring = ListingsExpiring::<T>::take(block).into_inner();
+				let rest = expiring.split_off(expiring.len().min((max - expired) as usize));
+
+				for kitty_id in expiring {
+					expired += 1;
+					ListingExpiry::<T>::remove(&kitty_id);
+					ForSale::<T>::remove(&kitty_id);
+					Kitties::<T>::mutate(&kitty_id, |kitty| {
+						if let Some(kitty) = kitty {
+							kitty.price = None;
+						}
+					});
+					Self::deposit_event(Event::ListingExpired { kitty: kitty_id });
+				}
+
+				if !rest.is_empty() {
+					// What is left of a bounded schedule stays within the bound
+					if let Ok(rest) = BoundedVec::<_, T::MaxListingExpiries>::try_from(rest) {
+						ListingsExpiring::<T>::insert(block, rest);
+					}
+					break
+				}
+				block = block.saturating_add(1u32.into());
+			}
+
+			ExpiryCursor::<T>::put(block);
+			(blocks, expired)
+		}
+
+		/// The account marketplace fees are paid to.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		// Fees are paid into the pallet account, which has to exist for fees below the
+		// existential deposit to be accepted. Returns whether the account had to be funded.
+		pub(crate) fn fund_account() -> bool {
+			let account_id = Self::account_id();
+			let min = T::Currency::minimum_balance();
+			if T::Currency::free_balance(&account_id) >= min {
+				return false
+			}
+			let _ = T::Currency::make_free_balance_be(&account_id, min);
+			true
+		}
+
+		/// The price a kitty can be bought for in the current block, either its fixed price or
+		/// the current price of its dutch auction.
+		///
+		/// Returns `None` while the kitty is rented out, as it cannot be bought then.
+		pub fn current_price(kitty_id: &[u8; 16]) -> Option<BalanceOf<T>> {
+			if Rentals::<T>::contains_key(kitty_id) {
+				return None
+			}
+			Self::fixed_price(kitty_id, &Kitties::<T>::get(kitty_id)?)
+				.or_else(|| Self::dutch_auction_price(kitty_id))
+		}
+
+		// the fixed price of a kitty, unless its listing expired and waits to be withdrawn
+		fn fixed_price(kitty_id: &[u8; 16], kitty: &Kitty<T>) -> Option<BalanceOf<T>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			match ListingExpiry::<T>::get(kitty_id) {
+				Some(expiry) if expiry <= now => None,
+				_ => kitty.price,
+			}
+		}
+
+		/// The marketplace fee the seller pays if the kitty is bought in the current block.
+		///
+		/// Returns `None` if the kitty is not for sale.
+		pub fn sale_fee(kitty_id: &[u8; 16]) -> Option<BalanceOf<T>> {
+			Self::current_price(kitty_id).map(|price| T::MarketplaceFee::get() * price)
+		}
+
+		/// The current asking price of a kitty in a dutch auction.
+		///
+		/// Returns `None` if the kitty is not in a dutch auction or the auction has expired.
+		pub fn dutch_auction_price(kitty_id: &[u8; 16]) -> Option<BalanceOf<T>> {
+			let auction = DutchAuctions::<T>::get(kitty_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			if now >= auction.end {
+				return None
+			}
+
+			let elapsed = now.saturating_sub(auction.start).saturated_into::<u128>();
+			let duration = auction.end.saturating_sub(auction.start).saturated_into::<u128>();
+			let decay = Perbill::from_rational(elapsed, duration) *
+				auction.start_price.saturating_sub(auction.floor_price);
+
+			Some(auction.start_price.saturating_sub(decay))
+		}
+
+		// close an auction at its end block: hand an english auction's kitty to the best bidder
+		// and pay the seller, or let a dutch auction expire
+		fn settle_auction(kitty_id: [u8; 16]) {
+			if DutchAuctions::<T>::take(&kitty_id).is_some() {
+				ForSale::<T>::remove(&kitty_id);
+				Self::deposit_event(Event::AuctionCancelled { kitty: kitty_id });
+				return
+			}
+
+			let auction = match Auctions::<T>::take(&kitty_id) {
+				Some(auction) => auction,
+				None => return,
+			};
+
+			if let Some((winner, price)) = auction.best_bid {
+				// Hand the kitty over and pay the seller straight out of the winning bid's
+				// reserve. No transaction wraps the hook, so both are undone if either fails
+				let settled = with_transaction(|| {
+					let result = Self::do_transfer(kitty_id, winner.clone()).and_then(|_| {
+						Self::pay_sale(&kitty_id, &winner, &auction.seller, price, true)
+					});
+					match result {
+						Ok(()) => TransactionOutcome::Commit(true),
+						Err(_) => TransactionOutcome::Rollback(false),
+					}
+				});
+				if settled {
+					Self::deposit_event(Event::AuctionSettled {
+						kitty: kitty_id,
+						seller: auction.seller,
+						winner,
+						price,
+					});
+					return
+				}
+
+				// Either step failed, give the winning bid back
+				T::Currency::unreserve(&winner, price);
+			}
+
+			Self::deposit_event(Event::AuctionCancelled { kitty: kitty_id });
+		}
+
+		/// Check that the indexes of the pallet agree with the kitties they index.
+		///
+		/// Reads the whole pallet storage, so it is only built for try-runtime, where it runs after
+		/// the storage migrations, and for the tests, which run it after every test case.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let (mut count, mut listed) = (0u64, 0usize);
+			for (kitty_id, kitty) in Kitties::<T>::iter() {
+				count += 1;
+				ensure!(
+					OwnedKitties::<T>::contains_key(&kitty.owner, kitty_id),
+					"kittens: kitty missing from its owner's index"
+				);
+				if kitty.price.is_some() || DutchAuctions::<T>::contains_key(&kitty_id) {
+					listed += 1;
+					ensure!(
+						ForSale::<T>::contains_key(&kitty_id),
+						"kittens: listed kitty missing from ForSale"
+					);
+				}
+			}
+			ensure!(
+				ForSale::<T>::iter_keys().count() == listed,
+				"kittens: ForSale holds a kitty that is not listed"
+			);
+			ensure!(
+				CountForKitties::<T>::get() == count,
+				"kittens: CountForKitties does not match the number of kitties"
+			);
+			ensure!(count <= T::MaxKittySupply::get(), "kittens: supply above MaxKittySupply");
+			ensure!(
+				MintedInBlock::<T>::get() <= T::MaxMintsPerBlock::get(),
+				"kittens: more kitties minted in the block than allowed"
+			);
+
+			// With every kitty indexed under its owner, this leaves no kitty indexed twice
+			for (owner, kitty_id, ()) in OwnedKitties::<T>::iter() {
+				ensure!(
+					Kitties::<T>::get(kitty_id).map_or(false, |kitty| kitty.owner == owner),
+					"kittens: kitty indexed under an account that does not own it"
+				);
+			}
+
+			let mut owned_total = 0u64;
+			for (owner, owned) in OwnedCount::<T>::iter() {
+				ensure!(
+					owned <= T::MaxKittiesOwned::get(),
+					"kittens: owner holds too many kitties"
+				);
+				ensure!(
+					OwnedKitties::<T>::iter_key_prefix(&owner).count() == owned as usize,
+					"kittens: OwnedCount does not match the owner's index"
+				);
+				owned_total += owned as u64;
+			}
+			ensure!(owned_total == count, "kittens: kitties indexed without an OwnedCount");
+
+			// A listed kitty is priced either by its fixed price or by its auction, never both
+			for (kitty_id, auction) in Auctions::<T>::iter() {
+				Self::check_auction_listing(&kitty_id, &auction.seller, auction.end)?;
+				ensure!(
+					!DutchAuctions::<T>::contains_key(&kitty_id),
+					"kittens: kitty in an english and a dutch auction"
+				);
+			}
+			for (kitty_id, auction) in DutchAuctions::<T>::iter() {
+				Self::check_auction_listing(&kitty_id, &auction.seller, auction.end)?;
+				ensure!(
+					auction.floor_price <= auction.start_price,
+					"kittens: dutch auction floor above its start price"
+				);
+			}
+			for (end, ending) in AuctionsEnding::<T>::iter() {
+				ensure!(
+					ending.iter().all(|kitty_id| {
+						Auctions::<T>::get(kitty_id).map(|auction| auction.end) == Some(end) ||
+							DutchAuctions::<T>::get(kitty_id).map(|auction| auction.end) ==
+								Some(end)
+					}),
+					"kittens: closing queue holds a kitty not auctioned until then"
+				);
+			}
+
+			for (kitty_id, rental) in Rentals::<T>::iter() {
+				let kitty =
+					Kitties::<T>::get(kitty_id).ok_or("kittens: rental of a missing kitty")?;
+				ensure!(kitty.owner != rental.renter, "kittens: kitty rented to its owner");
+				ensure!(!Self::is_auctioned(&kitty_id), "kittens: rented kitty is auctioned");
+				ensure!(
+					RentalsEnding::<T>::get(rental.end).contains(&kitty_id),
+					"kittens: rental not scheduled to end"
+				);
+			}
+			for (end, ending) in RentalsEnding::<T>::iter() {
+				ensure!(
+					ending.iter().all(|kitty_id| {
+						Rentals::<T>::get(kitty_id).map(|rental| rental.end) == Some(end)
+					}),
+					"kittens: rental queue holds a kitty not rented until then"
+				);
+			}
+			ensure!(
+				RentalOffers::<T>::iter_keys().all(|kitty_id| Kitties::<T>::contains_key(kitty_id)),
+				"kittens: rental offer for a missing kitty"
+			);
+			for (kitty_id, (_, deposit)) in KittyNames::<T>::iter() {
+				let kitty =
+					Kitties::<T>::get(kitty_id).ok_or("kittens: name of a missing kitty")?;
+				ensure!(
+					T::Currency::reserved_balance(&kitty.owner) >= deposit,
+					"kittens: name deposit not reserved from the owner"
+				);
+			}
+			for (kitty_id, swap) in Swaps::<T>::iter() {
+				ensure!(
+					Kitties::<T>::get(kitty_id).map_or(false, |kitty| kitty.owner == swap.proposer),
+					"kittens: swap proposed for a kitty the proposer does not own"
+				);
+			}
+			ensure!(
+				SaleHistory::<T>::iter_keys().all(|kitty_id| Kitties::<T>::contains_key(kitty_id)),
+				"kittens: sale history of a missing kitty"
+			);
+
+			for (kitty_id, expiry) in ListingExpiry::<T>::iter() {
+				ensure!(
+					Kitties::<T>::get(kitty_id).map_or(false, |kitty| kitty.price.is_some()),
+					"kittens: expiry of a kitty without a fixed price"
+				);
+				ensure!(
+					ListingsExpiring::<T>::get(expiry).contains(&kitty_id),
+					"kittens: listing expiry not scheduled"
+				);
+			}
+			let cursor = ExpiryCursor::<T>::get();
+			for (expiry, expiring) in ListingsExpiring::<T>::iter() {
+				ensure!(
+					cursor.map_or(true, |cursor| expiry >= cursor),
+					"kittens: listing expiries left behind the cursor"
+				);
+				ensure!(
+					expiring
+						.iter()
+						.all(|kitty_id| ListingExpiry::<T>::get(kitty_id) == Some(expiry)),
+					"kittens: expiry queue holds a kitty not listed until then"
+				);
+			}
+
+			for (owner, commit) in MintCommits::<T>::iter() {
+				ensure!(
+					CommitsExpiring::<T>::get(commit.expires).contains(&owner),
+					"kittens: mint commitment not scheduled to expire"
+				);
+				ensure!(
+					T::Currency::reserved_balance(&owner) >= commit.deposit,
+					"kittens: commit deposit not reserved from the owner"
+				);
+			}
+			for (expires, expiring) in CommitsExpiring::<T>::iter() {
+				ensure!(
+					expiring.iter().all(|owner| {
+						MintCommits::<T>::get(owner).map(|commit| commit.expires) == Some(expires)
+					}),
+					"kittens: commit queue holds an account not committed until then"
+				);
+			}
+
+			Ok(())
+		}
+
+		// an auctioned kitty is held by the seller, has no fixed price and is due to be closed
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_auction_listing(
+			kitty_id: &[u8; 16],
+			seller: &T::AccountId,
+			end: T::BlockNumber,
+		) -> Result<(), &'static str> {
+			let kitty = Kitties::<T>::get(kitty_id).ok_or("kittens: auction of a missing kitty")?;
+			ensure!(kitty.owner == *seller, "kittens: auction seller does not own the kitty");
+			ensure!(kitty.price.is_none(), "kittens: auctioned kitty has a fixed price");
+			ensure!(
+				AuctionsEnding::<T>::get(end).contains(kitty_id),
+				"kittens: auction not scheduled to close"
+			);
 			Ok(())
 		}
 	}
@@ -0,0 +1,357 @@
+//! Storage migrations of the kittens pallet
+//!
+//! The try-runtime checks of a migration only look at upgrades it takes part in. Its
+//! `pre_upgrade` records what to expect, under a temp storage key of its own, when it is going
+//! to run, either on its own or after the earlier migrations of the same upgrade. Its
+//! `post_upgrade` has nothing to check otherwise. All migrations run before any `post_upgrade`,
+//! so the checks expect at least their own storage version, and counting the kitties after a
+//! layout change decodes every value, which shows that none were left in the old layout.
+
+/// Version 1 adds the lineage and royalty fields to every stored kitty.
+pub mod v1 {
+	use crate::{BalanceOf, Config, Gender, Pallet};
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::Permill,
+		storage::migration,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	};
+
+	// name of the kitties map, written through the raw storage in layouts older than `Kitty`
+	pub(crate) const KITTIES: &[u8] = b"Kitties";
+
+	// A kitty as stored in version 0
+	#[derive(Encode, Decode)]
+	pub struct OldKitty<T: Config> {
+		pub dna: [u8; 16],
+		pub price: Option<BalanceOf<T>>,
+		pub gender: Gender,
+		pub owner: T::AccountId,
+	}
+
+	/// A kitty as stored in versions 1 and 2.
+	#[derive(Encode, Decode)]
+	pub struct KittyV1<T: Config> {
+		pub dna: [u8; 16],
+		pub price: Option<BalanceOf<T>>,
+		pub gender: Gender,
+		pub owner: T::AccountId,
+		pub parents: Option<([u8; 16], [u8; 16])>,
+		pub generation: u32,
+		pub creator: T::AccountId,
+		pub royalty: Permill,
+	}
+
+	/// Translate every kitty to the version 1 layout.
+	///
+	/// Minted kitties had no recorded lineage, so every existing kitty becomes a generation 0
+	/// kitty whose current owner is taken as its creator, with no royalty.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!("KITTENS_MIGRATION[v1]: skipped, on chain version is {:?}", on_chain);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let pallet = Pallet::<T>::name().as_bytes();
+			let mut translated = 0u64;
+			for (key, old) in migration::storage_iter::<OldKitty<T>>(pallet, KITTIES) {
+				let kitty = KittyV1::<T> {
+					dna: old.dna,
+					price: old.price,
+					gender: old.gender,
+					creator: old.owner.clone(),
+					owner: old.owner,
+					parents: None,
+					generation: 0,
+					royalty: Permill::zero(),
+				};
+				migration::put_storage_value(pallet, KITTIES, &key, kitty);
+				translated += 1;
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!("KITTENS_MIGRATION[v1]: translated {} kitties", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use crate::Kitties;
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			if Pallet::<T>::on_chain_storage_version() == 0 {
+				Self::set_temp_storage(Kitties::<T>::iter_keys().count() as u64, "v1_kitty_count");
+			}
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use crate::Kitties;
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let expected: u64 = match Self::get_temp_storage("v1_kitty_count") {
+				Some(expected) => expected,
+				None => return Ok(()),
+			};
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "kittens: expected version 1");
+			ensure!(Kitties::<T>::iter().count() as u64 == expected, "kittens: kitties lost");
+			ensure!(
+				Kitties::<T>::iter_values().all(|kitty| kitty.creator == kitty.owner),
+				"kittens: creator not backfilled from owner"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Version 2 replaces the `KittiesOwned` vector of every account with the `OwnedKitties` index
+/// and the `OwnedCount` counter.
+pub mod v2 {
+	use super::v1::{KittyV1, KITTIES};
+	use crate::{Config, OwnedCount, OwnedKitties, Pallet};
+	use frame_support::{
+		pallet_prelude::*,
+		storage::migration,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	};
+
+	// name of the removed vector index
+	const KITTIES_OWNED: &[u8] = b"KittiesOwned";
+
+	/// Rebuild the ownership index from the owner of every kitty and remove `KittiesOwned`.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 1 {
+				log::info!("KITTENS_MIGRATION[v2]: skipped, on chain version is {:?}", on_chain);
+				return T::DbWeight::get().reads(1)
+			}
+
+			// The owner stored on a kitty is authoritative, so the index is built from it
+			let mut indexed = 0u64;
+			let kitties = migration::storage_key_iter::<[u8; 16], KittyV1<T>, Twox64Concat>(
+				Pallet::<T>::name().as_bytes(),
+				KITTIES,
+			);
+			for (kitty_id, kitty) in kitties {
+				OwnedKitties::<T>::insert(&kitty.owner, kitty_id, ());
+				OwnedCount::<T>::mutate(&kitty.owner, |count| *count += 1);
+				indexed += 1;
+			}
+			migration::remove_storage_prefix(Pallet::<T>::name().as_bytes(), KITTIES_OWNED, &[]);
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!("KITTENS_MIGRATION[v2]: indexed {} kitties", indexed);
+			T::DbWeight::get().reads_writes(2 * indexed + 1, 2 * indexed + 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use crate::Kitties;
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			if Pallet::<T>::on_chain_storage_version() > 1 {
+				return Ok(())
+			}
+			// The old index must agree with the kitties it is rebuilt from
+			let owned: u64 =
+				migration::storage_key_iter::<T::AccountId, Vec<[u8; 16]>, Twox64Concat>(
+					Pallet::<T>::name().as_bytes(),
+					KITTIES_OWNED,
+				)
+				.map(|(_, owned)| owned.len() as u64)
+				.sum();
+			ensure!(
+				owned == Kitties::<T>::iter_keys().count() as u64,
+				"kittens: KittiesOwned out of sync with Kitties"
+			);
+			Self::set_temp_storage(owned, "v2_owned_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use crate::Kitties;
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let expected: u64 = match Self::get_temp_storage("v2_owned_count") {
+				Some(expected) => expected,
+				None => return Ok(()),
+			};
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "kittens: expected version 2");
+			ensure!(
+				migration::storage_key_iter::<T::AccountId, Vec<[u8; 16]>, Twox64Concat>(
+					Pallet::<T>::name().as_bytes(),
+					KITTIES_OWNED,
+				)
+				.next()
+				.is_none(),
+				"kittens: KittiesOwned not removed"
+			);
+			ensure!(
+				OwnedCount::<T>::iter_values().map(|count| count as u64).sum::<u64>() == expected,
+				"kittens: owned count mismatch"
+			);
+			ensure!(
+				Kitties::<T>::iter().all(|(kitty_id, kitty)| OwnedKitties::<T>::contains_key(
+					&kitty.owner,
+					kitty_id
+				)),
+				"kittens: kitty missing from the owner index"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Version 3 adds the lock to every stored kitty.
+pub mod v3 {
+	use super::v1::KittyV1;
+	use crate::{Config, Kitties, Kitty, Pallet};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	/// Translate every kitty to the version 3 layout, leaving it unlocked.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 2 {
+				log::info!("KITTENS_MIGRATION[v3]: skipped, on chain version is {:?}", on_chain);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Kitties::<T>::translate::<KittyV1<T>, _>(|_, old| {
+				translated += 1;
+				Some(Kitty {
+					dna: old.dna,
+					price: old.price,
+					gender: old.gender,
+					owner: old.owner,
+					parents: old.parents,
+					generation: old.generation,
+					creator: old.creator,
+					royalty: old.royalty,
+					lock: None,
+				})
+			});
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!("KITTENS_MIGRATION[v3]: translated {} kitties", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			if Pallet::<T>::on_chain_storage_version() <= 2 {
+				Self::set_temp_storage(Kitties::<T>::iter_keys().count() as u64, "v3_kitty_count");
+			}
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 3, "kittens: expected version 3");
+			if let Some(expected) = Self::get_temp_storage::<u64>("v3_kitty_count") {
+				ensure!(Kitties::<T>::iter().count() as u64 == expected, "kittens: kitties lost");
+				ensure!(
+					Kitties::<T>::iter_values().all(|kitty| kitty.lock.is_none()),
+					"kittens: migrated kitty is locked"
+				);
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Version 4 indexes the kitties with a fixed price or in a dutch auction in `ForSale`.
+pub mod v4 {
+	use crate::{Config, DutchAuctions, ForSale, Kitties, Pallet};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	/// Build the `ForSale` index from the price of every kitty and the dutch auctions.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 3 {
+				log::info!("KITTENS_MIGRATION[v4]: skipped, on chain version is {:?}", on_chain);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (mut read, mut indexed) = (0u64, 0u64);
+			for (kitty_id, kitty) in Kitties::<T>::iter() {
+				read += 2;
+				if kitty.price.is_some() || DutchAuctions::<T>::contains_key(&kitty_id) {
+					ForSale::<T>::insert(&kitty_id, ());
+					indexed += 1;
+				}
+			}
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			log::info!("KITTENS_MIGRATION[v4]: indexed {} kitties for sale", indexed);
+			T::DbWeight::get().reads_writes(read + 1, indexed + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 4, "kittens: expected version 4");
+			// The invariants check the index against the listings
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
+
+/// Chains that started before the marketplace fee have no pallet account to pay fees into.
+pub mod fee_account {
+	use crate::{Config, Pallet};
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Fund the pallet account with the existential deposit if it does not exist.
+	///
+	/// The migration does not depend on the storage version, and does nothing once the account
+	/// exists.
+	pub struct FundFeeAccount<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for FundFeeAccount<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !Pallet::<T>::fund_account() {
+				return T::DbWeight::get().reads(1)
+			}
+
+			log::info!("KITTENS_MIGRATION[fee_account]: funded the pallet account");
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::Currency;
+
+			ensure!(
+				T::Currency::free_balance(&Pallet::<T>::account_id()) >=
+					T::Currency::minimum_balance(),
+				"kittens: pallet account not funded"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,10 +1,15 @@
-use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use crate as pallet_kittens;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, Randomness},
+	PalletId,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -17,8 +22,9 @@ frame_support::construct_runtime!(
 		NodeBlock = Block,
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
-		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
+		System: frame_system,
+		Balances: pallet_balances,
+		KittiesModule: pallet_kittens,
 	}
 );
 
@@ -40,7 +46,7 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -49,11 +55,101 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
-impl pallet_template::Config for Test {
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
 	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+// Deterministic randomness: the same subject in the same block yields the same output.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block_number = System::block_number();
+		let seed = sp_io::hashing::blake2_256(&[subject, &block_number.to_le_bytes()].concat());
+		(H256::from(seed), block_number)
+	}
+}
+
+parameter_types! {
+	pub const MaxRoyalty: Permill = Permill::from_percent(20);
+	pub const MarketplaceFee: Permill = Permill::from_percent(5);
+	pub const KittiesPalletId: PalletId = PalletId(*b"py/kitty");
+	// the existential deposit, supply limits and the mint fee are changed by the tests that
+	// cover them
+	pub static ExistentialDeposit: u64 = 1;
+	pub static MaxKittySupply: u64 = 100;
+	pub static MaxMintsPerBlock: u32 = 10;
+	pub static MintFee: u64 = 0;
 }
 
+impl pallet_kittens::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type MaxKittiesOwned = ConstU32<3>;
+	type KittyRandomness = TestRandomness;
+	type BreedingCooldown = ConstU64<10>;
+	type MaxAuctionsPerBlock = ConstU32<2>;
+	type MaxAuctionDuration = ConstU64<100>;
+	type MaxOffersPerKitty = ConstU32<2>;
+	type MaxRoyalty = MaxRoyalty;
+	type MarketplaceFee = MarketplaceFee;
+	type PalletId = KittiesPalletId;
+	type MaxRentalsPerBlock = ConstU32<2>;
+	type MaxKittySupply = MaxKittySupply;
+	type MaxMintsPerBlock = MaxMintsPerBlock;
+	type MintFee = MintFee;
+	type NameDeposit = ConstU64<10>;
+	type Slashed = ();
+	type MinNameLength = ConstU32<3>;
+	type MaxNameLength = ConstU32<8>;
+	type MaxSaleHistory = ConstU32<2>;
+	type StatsPeriod = ConstU64<10>;
+	type MaxListingExpiries = ConstU32<2>;
+	type MaxBatchSize = ConstU32<3>;
+	type RevealDelay = ConstU64<2>;
+	type RevealWindow = ConstU64<3>;
+	type CommitDeposit = ConstU64<10>;
+	type MaxCommitsPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+// Accounts funded at genesis and their free balance.
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const INITIAL_BALANCE: u64 = 1_000;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(BOB, INITIAL_BALANCE),
+			(CHARLIE, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	// Events are not deposited on the genesis block
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+// Run a test on the mock genesis and check the pallet invariants once it is done.
+pub fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		KittiesModule::do_try_state().unwrap();
+	});
 }
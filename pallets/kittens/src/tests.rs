@@ -1,20 +1,2578 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	genome::{self, EyeShape, FurColour, Pattern, Traits},
+	migrations::{
+		fee_account::FundFeeAccount,
+		v1::{KittyV1, MigrateToV1},
+		v2::MigrateToV2,
+		v3::MigrateToV3,
+		v4::MigrateToV4,
+	},
+	mock::*,
+	Approvals, Auctions, AuctionsEnding, BreedingCooldowns, CommitsExpiring, CountForKitties,
+	DutchAuctions, Error, Event, ForSale, Gender, Kitties, KittyNames, ListingExpiry,
+	ListingsExpiring, Lock, MintCommits, OfferCount, Offers, OperatorApprovals, OwnedCount,
+	OwnedKitties, RentalOffers, Rentals, RentalsEnding, Sale, SaleHistory, Swaps,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		tokens::nonfungible::{Inspect, InspectEnumerable, Mutate, Transfer},
+		GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	},
+	StorageHasher, Twox64Concat,
+};
+use sp_core::H256;
+use sp_runtime::Permill;
+
+// mint a kitty with a predictable dna straight through the pallet helper
+fn mint_kitty(owner: u64, seed: u8, gender: Gender) -> [u8; 16] {
+	assert_ok!(KittiesModule::mint(&owner, [seed; 16], gender));
+	[seed; 16]
+}
+
+// the kitties held by `owner`, in dna order
+fn owned_by(owner: u64) -> Vec<[u8; 16]> {
+	let mut owned: Vec<_> = OwnedKitties::<Test>::iter_key_prefix(owner).collect();
+	owned.sort();
+	assert_eq!(OwnedCount::<Test>::get(owner) as usize, owned.len());
+	owned
+}
+
+// rewrite a kitty in the layout of storage versions 1 and 2
+fn put_v1_kitty(kitty_id: [u8; 16]) {
+	let kitty = Kitties::<Test>::get(kitty_id).unwrap();
+	frame_support::storage::unhashed::put(
+		&Kitties::<Test>::hashed_key_for(kitty_id),
+		&KittyV1::<Test> {
+			dna: kitty.dna,
+			price: kitty.price,
+			gender: kitty.gender,
+			owner: kitty.owner,
+			parents: kitty.parents,
+			generation: kitty.generation,
+			creator: kitty.creator,
+			royalty: kitty.royalty,
+		},
+	);
+}
+
+// mint a kitty for `owner` and list it at `price`
+fn list_kitty(owner: u64, seed: u8, price: u64) -> [u8; 16] {
+	let kitty_id = mint_kitty(owner, seed, Gender::Male);
+	assert_ok!(KittiesModule::set_price(Origin::signed(owner), kitty_id, Some(price), None));
+	kitty_id
+}
+
+#[test]
+fn create_kitty_works() {
+	build_and_execute(|| {
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(ALICE)));
+
+		assert_eq!(CountForKitties::<Test>::get(), 1);
+		let owned = owned_by(ALICE);
+		assert_eq!(owned.len(), 1);
+		let kitty = Kitties::<Test>::get(owned[0]).unwrap();
+		assert_eq!(kitty.owner, ALICE);
+		assert_eq!(kitty.price, None);
+		System::assert_last_event(Event::Created { kitty: owned[0], owner: ALICE }.into());
+	});
+}
+
+#[test]
+fn create_kitty_fails_for_duplicate_dna() {
+	build_and_execute(|| {
+		// the mock randomness only changes between blocks, so the same dna comes up twice
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(ALICE)));
+		assert_noop!(
+			KittiesModule::create_kitty(Origin::signed(BOB)),
+			Error::<Test>::DuplicateKitty
+		);
+	});
+}
+
+#[test]
+fn mint_fails_when_owning_too_many() {
+	build_and_execute(|| {
+		for seed in 0..3 {
+			mint_kitty(ALICE, seed, Gender::Female);
+		}
+		assert_noop!(
+			KittiesModule::mint(&ALICE, [3; 16], Gender::Female),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn mint_fails_on_count_overflow() {
+	// the count is corrupted on purpose, so the invariants are not checked
+	new_test_ext().execute_with(|| {
+		CountForKitties::<Test>::put(u64::MAX);
+		assert_noop!(KittiesModule::mint(&ALICE, [0; 16], Gender::Male), Error::<Test>::Overflow);
+	});
+}
+
+#[test]
+fn transfer_works() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id));
+
+		let kitty = Kitties::<Test>::get(kitty_id).unwrap();
+		assert_eq!(kitty.owner, BOB);
+		// transferring unlists the kitty
+		assert_eq!(kitty.price, None);
+		assert!(owned_by(ALICE).is_empty());
+		assert_eq!(owned_by(BOB), vec![kitty_id]);
+		System::assert_last_event(
+			Event::Transferred { from: ALICE, to: BOB, kitty: kitty_id }.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(ALICE), BOB, [0; 16]),
+			Error::<Test>::NoKitty
+		);
+
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(BOB), CHARLIE, kitty_id),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(ALICE), ALICE, kitty_id),
+			Error::<Test>::TransferToSelf
+		);
+
+		for seed in 1..4 {
+			mint_kitty(BOB, seed, Gender::Female);
+		}
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn set_price_works() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(ALICE), [1; 16], Some(10), None),
+			Error::<Test>::NoKitty
+		);
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(BOB), kitty_id, Some(10), None),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), None));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().price, Some(10));
+		System::assert_last_event(
+			Event::PriceSet { kitty: kitty_id, price: Some(10), expiry: None }.into(),
+		);
+
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, None, None));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().price, None);
+	});
+}
+
+#[test]
+fn buy_kitty_works() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+
+		// paying up to a higher maximum only charges the asking price
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 150));
+
+		let kitty = Kitties::<Test>::get(kitty_id).unwrap();
+		assert_eq!(kitty.owner, BOB);
+		assert_eq!(kitty.price, None);
+		assert!(owned_by(ALICE).is_empty());
+		assert_eq!(owned_by(BOB), vec![kitty_id]);
+		// the seller pays the marketplace fee out of the price
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 95);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 100);
+		assert_eq!(Balances::free_balance(KittiesModule::account_id()), 5);
+		System::assert_has_event(
+			Event::Transferred { from: ALICE, to: BOB, kitty: kitty_id }.into(),
+		);
+		System::assert_last_event(
+			Event::Sold { seller: ALICE, buyer: BOB, kitty: kitty_id, price: 100, fee: 5 }.into(),
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_for_missing_or_own_kitty() {
+	build_and_execute(|| {
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), [0; 16], 100),
+			Error::<Test>::NoKitty
+		);
+
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(ALICE), kitty_id, 100),
+			Error::<Test>::TransferToSelf
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_when_not_for_sale() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_respects_max_price() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 99),
+			Error::<Test>::BidPriceTooLow
+		);
+
+		// a price bump after the buyer signed is not charged
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(200), None));
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100),
+			Error::<Test>::BidPriceTooLow
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_when_buyer_owns_too_many() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		for seed in 1..4 {
+			mint_kitty(BOB, seed, Gender::Female);
+		}
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_leaves_indexes_untouched_when_payment_fails() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, INITIAL_BALANCE);
+
+		// the buyer has to stay above the existential deposit
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, INITIAL_BALANCE),
+			pallet_balances::Error::<Test>::KeepAlive
+		);
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, ALICE);
+		assert_eq!(owned_by(ALICE), vec![kitty_id]);
+		assert!(owned_by(BOB).is_empty());
+	});
+}
+
+#[test]
+fn breed_kitty_works() {
+	build_and_execute(|| {
+		let mom = mint_kitty(ALICE, 0x0f, Gender::Female);
+		let dad = mint_kitty(ALICE, 0xf0, Gender::Male);
+		assert_ok!(KittiesModule::breed_kitty(Origin::signed(ALICE), mom, dad));
+
+		assert_eq!(CountForKitties::<Test>::get(), 3);
+		let owned = owned_by(ALICE);
+		assert_eq!(owned.len(), 3);
+		let child_id = owned.into_iter().find(|id| *id != mom && *id != dad).unwrap();
+		let child = Kitties::<Test>::get(child_id).unwrap();
+		assert_eq!(child.owner, ALICE);
+		assert_eq!(child.creator, ALICE);
+		assert_eq!(child.parents, Some((mom, dad)));
+		assert_eq!(child.generation, 1);
+		// every gene comes from one of the parents
+		for (i, gene) in child.dna.iter().enumerate() {
+			assert_eq!(gene & !(mom[i] | dad[i]), 0);
+		}
+
+		// both parents rest for `BreedingCooldown` blocks
+		assert_eq!(BreedingCooldowns::<Test>::get(mom), Some(11));
+		assert_eq!(BreedingCooldowns::<Test>::get(dad), Some(11));
+		System::assert_last_event(Event::Bred { kitty: child.dna, mom, dad, owner: ALICE }.into());
+	});
+}
+
+#[test]
+fn breed_kitty_fails_for_invalid_parents() {
+	build_and_execute(|| {
+		let mom = mint_kitty(ALICE, 0, Gender::Female);
+		let other_mom = mint_kitty(ALICE, 1, Gender::Female);
+		let dad = mint_kitty(BOB, 2, Gender::Male);
+
+		assert_noop!(
+			KittiesModule::breed_kitty(Origin::signed(ALICE), mom, [3; 16]),
+			Error::<Test>::NoKitty
+		);
+		assert_noop!(
+			KittiesModule::breed_kitty(Origin::signed(ALICE), mom, dad),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::breed_kitty(Origin::signed(ALICE), mom, other_mom),
+			Error::<Test>::GenderMismatch
+		);
+		assert_noop!(
+			KittiesModule::breed_kitty(Origin::signed(BOB), dad, dad),
+			Error::<Test>::GenderMismatch
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_enforces_cooldown() {
+	build_and_execute(|| {
+		let mom = mint_kitty(ALICE, 0x0f, Gender::Female);
+		let dad = mint_kitty(ALICE, 0xf0, Gender::Male);
+		assert_ok!(KittiesModule::breed_kitty(Origin::signed(ALICE), mom, dad));
+
+		// make room for the next child
+		let child = owned_by(ALICE).into_iter().find(|id| *id != mom && *id != dad).unwrap();
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, child));
+
+		assert_noop!(
+			KittiesModule::breed_kitty(Origin::signed(ALICE), mom, dad),
+			Error::<Test>::BreedingCooldown
+		);
+
+		System::set_block_number(11);
+		assert_ok!(KittiesModule::breed_kitty(Origin::signed(ALICE), mom, dad));
+		assert_eq!(BreedingCooldowns::<Test>::get(mom), Some(21));
+	});
+}
+
+#[test]
+fn auction_is_settled_to_best_bidder() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 500);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		// auctioned kitties are not for sale at a fixed price
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().price, None);
+		assert_eq!(AuctionsEnding::<Test>::get(10).into_inner(), vec![kitty_id]);
+
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 100));
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+
+		// being outbid releases the reserved funds
+		assert_ok!(KittiesModule::bid(Origin::signed(CHARLIE), kitty_id, 150));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 150);
+		System::assert_last_event(
+			Event::BidPlaced { kitty: kitty_id, bidder: CHARLIE, amount: 150 }.into(),
+		);
+
+		System::set_block_number(10);
+		KittiesModule::on_initialize(10);
+
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, CHARLIE);
+		assert_eq!(owned_by(CHARLIE), vec![kitty_id]);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 150);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 150);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert!(Auctions::<Test>::get(kitty_id).is_none());
+		assert!(AuctionsEnding::<Test>::get(10).is_empty());
+		System::assert_last_event(
+			Event::AuctionSettled { kitty: kitty_id, seller: ALICE, winner: CHARLIE, price: 150 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn auction_is_cancelled_when_the_seller_cannot_be_paid() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 150));
+
+		// owning a kitty does not keep the seller's account alive
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), CHARLIE, INITIAL_BALANCE));
+		assert!(!System::account_exists(&ALICE));
+
+		System::set_block_number(10);
+		KittiesModule::on_initialize(10);
+
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, ALICE);
+		assert!(owned_by(BOB).is_empty());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert!(SaleHistory::<Test>::get(kitty_id).is_empty());
+		assert!(Auctions::<Test>::get(kitty_id).is_none());
+		System::assert_last_event(Event::AuctionCancelled { kitty: kitty_id }.into());
+	});
+}
+
+#[test]
+fn auction_without_bids_is_cancelled_at_end() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+
+		KittiesModule::on_initialize(10);
+
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, ALICE);
+		assert!(Auctions::<Test>::get(kitty_id).is_none());
+		System::assert_last_event(Event::AuctionCancelled { kitty: kitty_id }.into());
+	});
+}
+
+#[test]
+fn raising_own_bid_reserves_the_difference() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 600));
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 900));
+		assert_eq!(Balances::reserved_balance(BOB), 900);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 900);
+	});
+}
+
+#[test]
+fn start_auction_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(ALICE), [9; 16], 100, 10),
+			Error::<Test>::NoKitty
+		);
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(BOB), kitty_id, 100, 10),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 1),
+			Error::<Test>::InvalidAuctionEnd
+		);
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 102),
+			Error::<Test>::InvalidAuctionEnd
+		);
+
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 20),
+			Error::<Test>::InAuction
+		);
+
+		// only `MaxAuctionsPerBlock` auctions can end together
+		let second = mint_kitty(ALICE, 1, Gender::Male);
+		let third = mint_kitty(ALICE, 2, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), second, 100, 10));
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(ALICE), third, 100, 10),
+			Error::<Test>::TooManyAuctions
+		);
+	});
+}
+
+#[test]
+fn auctioned_kitty_cannot_be_transferred_or_listed() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id),
+			Error::<Test>::InAuction
+		);
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), None),
+			Error::<Test>::InAuction
+		);
+	});
+}
+
+#[test]
+fn bid_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(BOB), kitty_id, 100),
+			Error::<Test>::NotInAuction
+		);
+
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(ALICE), kitty_id, 100),
+			Error::<Test>::TransferToSelf
+		);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(BOB), kitty_id, 99),
+			Error::<Test>::BidPriceTooLow
+		);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(BOB), kitty_id, INITIAL_BALANCE + 1),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 200));
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(CHARLIE), kitty_id, 200),
+			Error::<Test>::BidPriceTooLow
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(CHARLIE), kitty_id, 300),
+			Error::<Test>::AuctionEnded
+		);
+	});
+}
+
+#[test]
+fn bid_fails_when_bidder_owns_too_many() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		for seed in 1..4 {
+			mint_kitty(BOB, seed, Gender::Female);
+		}
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(BOB), kitty_id, 100),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn cancel_auction_works_only_without_bids() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::cancel_auction(Origin::signed(ALICE), kitty_id),
+			Error::<Test>::NotInAuction
+		);
+
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_noop!(
+			KittiesModule::cancel_auction(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::cancel_auction(Origin::signed(ALICE), kitty_id));
+		assert!(Auctions::<Test>::get(kitty_id).is_none());
+		assert!(AuctionsEnding::<Test>::get(10).is_empty());
+
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 100));
+		assert_noop!(
+			KittiesModule::cancel_auction(Origin::signed(ALICE), kitty_id),
+			Error::<Test>::AuctionHasBids
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_price_decays_linearly() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 50);
+		assert_ok!(KittiesModule::start_dutch_auction(
+			Origin::signed(ALICE),
+			kitty_id,
+			500,
+			100,
+			11
+		));
+		// the fixed price listing is replaced by the auction
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().price, None);
+
+		assert_eq!(KittiesModule::dutch_auction_price(&kitty_id), Some(500));
+		System::set_block_number(6);
+		assert_eq!(KittiesModule::dutch_auction_price(&kitty_id), Some(300));
+		System::set_block_number(10);
+		assert_eq!(KittiesModule::dutch_auction_price(&kitty_id), Some(140));
+		// expired listings have no price
+		System::set_block_number(11);
+		assert_eq!(KittiesModule::dutch_auction_price(&kitty_id), None);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 500),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_sells_at_current_price() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_dutch_auction(
+			Origin::signed(ALICE),
+			kitty_id,
+			500,
+			100,
+			11
+		));
+
+		System::set_block_number(6);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 299),
+			Error::<Test>::BidPriceTooLow
+		);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 500));
+
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, BOB);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 285);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 300);
+		assert!(DutchAuctions::<Test>::get(kitty_id).is_none());
+		assert!(AuctionsEnding::<Test>::get(11).is_empty());
+		System::assert_last_event(
+			Event::Sold { seller: ALICE, buyer: BOB, kitty: kitty_id, price: 300, fee: 15 }.into(),
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_expires_at_end() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_dutch_auction(
+			Origin::signed(ALICE),
+			kitty_id,
+			500,
+			100,
+			11
+		));
+
+		System::set_block_number(11);
+		KittiesModule::on_initialize(11);
+
+		assert!(DutchAuctions::<Test>::get(kitty_id).is_none());
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, ALICE);
+		System::assert_last_event(Event::AuctionCancelled { kitty: kitty_id }.into());
+
+		// the kitty can be listed again
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), None));
+	});
+}
+
+#[test]
+fn start_dutch_auction_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::start_dutch_auction(Origin::signed(BOB), kitty_id, 500, 100, 11),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::start_dutch_auction(Origin::signed(ALICE), kitty_id, 100, 500, 11),
+			Error::<Test>::InvalidPriceRange
+		);
+		assert_noop!(
+			KittiesModule::start_dutch_auction(Origin::signed(ALICE), kitty_id, 500, 100, 1),
+			Error::<Test>::InvalidAuctionEnd
+		);
+
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_noop!(
+			KittiesModule::start_dutch_auction(Origin::signed(ALICE), kitty_id, 500, 100, 11),
+			Error::<Test>::InAuction
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_can_be_cancelled() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_dutch_auction(
+			Origin::signed(ALICE),
+			kitty_id,
+			500,
+			100,
+			11
+		));
+		assert_noop!(
+			KittiesModule::cancel_auction(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::cancel_auction(Origin::signed(ALICE), kitty_id));
+		assert!(DutchAuctions::<Test>::get(kitty_id).is_none());
+		assert!(AuctionsEnding::<Test>::get(11).is_empty());
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 500),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn make_offer_reserves_funds() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 10));
+
+		assert_eq!(Balances::reserved_balance(BOB), 200);
+		assert_eq!(Offers::<Test>::get(kitty_id, BOB).unwrap().amount, 200);
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 1);
+		System::assert_last_event(
+			Event::OfferMade { kitty: kitty_id, bidder: BOB, amount: 200, expiry: 10 }.into(),
+		);
+
+		// a new offer replaces the previous one
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 150, 20));
+		assert_eq!(Balances::reserved_balance(BOB), 150);
+		assert_eq!(Offers::<Test>::get(kitty_id, BOB).unwrap().expiry, 20);
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 1);
+	});
+}
+
+#[test]
+fn make_offer_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(BOB), [0; 16], 200, 10),
+			Error::<Test>::NoKitty
+		);
+
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(ALICE), kitty_id, 200, 10),
+			Error::<Test>::TransferToSelf
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 1),
+			Error::<Test>::InvalidOfferExpiry
+		);
+		// offers below the existential deposit could fill the slots for free
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 0, 10),
+			Error::<Test>::OfferTooLow
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(BOB), kitty_id, INITIAL_BALANCE + 1, 10),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		// only `MaxOffersPerKitty` bidders can have an open offer
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 10));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 200, 10));
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(4), kitty_id, 200, 10),
+			Error::<Test>::TooManyOffers
+		);
+	});
+}
+
+#[test]
+fn new_offer_takes_the_place_of_an_expired_or_lower_one() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), 4, 100));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 100, 5));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 50, 10));
+
+		// the expired offer makes room, even for a lower one
+		System::set_block_number(5);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(4), kitty_id, 10, 10));
+		System::assert_has_event(Event::OfferDropped { kitty: kitty_id, bidder: BOB }.into());
+		assert!(Offers::<Test>::get(kitty_id, BOB).is_none());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 2);
+
+		// otherwise the lowest offer has to be beaten
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 10, 10),
+			Error::<Test>::TooManyOffers
+		);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 20, 10));
+		System::assert_has_event(Event::OfferDropped { kitty: kitty_id, bidder: 4 }.into());
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Offers::<Test>::get(kitty_id, BOB).unwrap().amount, 20);
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 2);
+	});
+}
+
+#[test]
+fn owner_can_reject_offers() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 10));
+		assert_noop!(
+			KittiesModule::reject_offer(Origin::signed(CHARLIE), kitty_id, BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::reject_offer(Origin::signed(ALICE), kitty_id, CHARLIE),
+			Error::<Test>::NoOffer
+		);
+
+		assert_ok!(KittiesModule::reject_offer(Origin::signed(ALICE), kitty_id, BOB));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert!(Offers::<Test>::get(kitty_id, BOB).is_none());
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 0);
+		System::assert_last_event(Event::OfferRejected { kitty: kitty_id, bidder: BOB }.into());
+	});
+}
+
+#[test]
+fn withdraw_offer_releases_funds() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::withdraw_offer(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NoOffer
+		);
+
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 10));
+		assert_ok!(KittiesModule::withdraw_offer(Origin::signed(BOB), kitty_id));
+
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert!(Offers::<Test>::get(kitty_id, BOB).is_none());
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 0);
+		System::assert_last_event(Event::OfferWithdrawn { kitty: kitty_id, bidder: BOB }.into());
+	});
+}
+
+#[test]
+fn accept_offer_works() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 10));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 300, 10));
+
+		assert_ok!(KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, BOB));
+
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, BOB);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 200);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 200);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		// the other offers were made to the previous owner
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert!(Offers::<Test>::iter_prefix(kitty_id).next().is_none());
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 0);
+		System::assert_last_event(
+			Event::OfferAccepted { kitty: kitty_id, seller: ALICE, buyer: BOB, price: 200 }.into(),
+		);
+	});
+}
+
+#[test]
+fn accept_offer_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, BOB),
+			Error::<Test>::NoOffer
+		);
+
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 10));
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(CHARLIE), kitty_id, BOB),
+			Error::<Test>::NotOwner
+		);
+
+		for seed in 1..4 {
+			mint_kitty(BOB, seed, Gender::Female);
+		}
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, BOB),
+			Error::<Test>::TooManyOwned
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, BOB),
+			Error::<Test>::OfferExpired
+		);
+	});
+}
+
+#[test]
+fn accept_offer_fails_when_the_reserve_is_short() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 200, 10));
+
+		// part of the offer's reserve is released behind the pallet's back
+		Balances::unreserve(&BOB, 50);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, BOB),
+			Error::<Test>::InsufficientReserve
+		);
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, ALICE);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn changing_owner_releases_open_offers() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 50, 10));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 60, 10));
+
+		// a sale releases the buyer's own offer too
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 0);
+
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 60, 10));
+		assert_ok!(KittiesModule::transfer(Origin::signed(BOB), ALICE, kitty_id));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert!(Offers::<Test>::get(kitty_id, CHARLIE).is_none());
+	});
+}
+
+#[test]
+fn royalty_is_paid_to_the_creator_on_resale() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_royalty(
+			Origin::signed(ALICE),
+			kitty_id,
+			Permill::from_percent(10)
+		));
+		System::assert_last_event(
+			Event::RoyaltySet { kitty: kitty_id, royalty: Permill::from_percent(10) }.into(),
+		);
+
+		// the creator selling their own kitty only pays the marketplace fee
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(100), None));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100));
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 95);
+
+		assert_ok!(KittiesModule::set_price(Origin::signed(BOB), kitty_id, Some(200), None));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(CHARLIE), kitty_id, 200));
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 95 + 20);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 100 + 200 - 20 - 10);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 200);
+		System::assert_has_event(
+			Event::RoyaltyPaid { kitty: kitty_id, creator: ALICE, amount: 20 }.into(),
+		);
+	});
+}
+
+#[test]
+fn royalty_is_paid_on_accepted_offers() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_royalty(
+			Origin::signed(ALICE),
+			kitty_id,
+			Permill::from_percent(20)
+		));
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id));
+
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 300, 10));
+		assert_ok!(KittiesModule::accept_offer(Origin::signed(BOB), kitty_id, CHARLIE));
+
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 60);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + 240);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		System::assert_has_event(
+			Event::RoyaltyPaid { kitty: kitty_id, creator: ALICE, amount: 60 }.into(),
+		);
+	});
+}
+
+#[test]
+fn set_royalty_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		assert_noop!(
+			KittiesModule::set_royalty(Origin::signed(ALICE), [0; 16], Permill::zero()),
+			Error::<Test>::NoKitty
+		);
+
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::set_royalty(Origin::signed(ALICE), kitty_id, Permill::from_percent(21)),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_noop!(
+			KittiesModule::set_royalty(Origin::signed(BOB), kitty_id, Permill::zero()),
+			Error::<Test>::NotCreator
+		);
+
+		// the creator cannot change the royalty once the kitty has been sold on
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id));
+		assert_noop!(
+			KittiesModule::set_royalty(Origin::signed(ALICE), kitty_id, Permill::zero()),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_backfills_creator() {
+	// the kitty is written without its index, so the invariants are not checked
+	new_test_ext().execute_with(|| {
+		let kitty_id = [7; 16];
+		// a kitty in the version 0 layout of (dna, price, gender, owner)
+		frame_support::storage::unhashed::put(
+			&Kitties::<Test>::hashed_key_for(kitty_id),
+			&(kitty_id, Some(50u64), Gender::Female, BOB),
+		);
+		StorageVersion::new(0).put::<KittiesModule>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		let kitty: KittyV1<Test> =
+			frame_support::storage::unhashed::get(&Kitties::<Test>::hashed_key_for(kitty_id))
+				.unwrap();
+		assert_eq!(kitty.owner, BOB);
+		assert_eq!(kitty.creator, BOB);
+		assert_eq!(kitty.price, Some(50));
+		assert_eq!(kitty.gender, Gender::Female);
+		assert_eq!(kitty.parents, None);
+		assert_eq!(kitty.generation, 0);
+		assert_eq!(kitty.royalty, Permill::zero());
+		assert_eq!(KittiesModule::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn sale_fee_is_quoted_ahead_of_purchase() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_eq!(KittiesModule::sale_fee(&kitty_id), None);
+
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(200), None));
+		assert_eq!(KittiesModule::current_price(&kitty_id), Some(200));
+		assert_eq!(KittiesModule::sale_fee(&kitty_id), Some(10));
+
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 200));
+		assert_eq!(Balances::free_balance(KittiesModule::account_id()), 10);
+		assert_eq!(KittiesModule::sale_fee(&kitty_id), None);
+	});
+}
+
+#[test]
+fn burn_kitty_works() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		let other = mint_kitty(ALICE, 1, Gender::Female);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 50, 10));
+
+		assert_ok!(KittiesModule::burn_kitty(Origin::signed(ALICE), kitty_id));
+
+		assert!(Kitties::<Test>::get(kitty_id).is_none());
+		assert_eq!(owned_by(ALICE), vec![other]);
+		assert_eq!(CountForKitties::<Test>::get(), 1);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(OfferCount::<Test>::get(kitty_id), 0);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100),
+			Error::<Test>::NoKitty
+		);
+		System::assert_last_event(Event::Burned { kitty: kitty_id, owner: ALICE }.into());
+
+		// the same dna can be minted again
+		mint_kitty(BOB, 0, Gender::Male);
+	});
+}
+
+#[test]
+fn burn_kitty_closes_dutch_auction() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_dutch_auction(
+			Origin::signed(ALICE),
+			kitty_id,
+			500,
+			100,
+			11
+		));
+
+		assert_ok!(KittiesModule::burn_kitty(Origin::signed(ALICE), kitty_id));
+
+		assert!(DutchAuctions::<Test>::get(kitty_id).is_none());
+		assert!(AuctionsEnding::<Test>::get(11).is_empty());
+	});
+}
+
+#[test]
+fn burn_kitty_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		assert_noop!(
+			KittiesModule::burn_kitty(Origin::signed(ALICE), [0; 16]),
+			Error::<Test>::NoKitty
+		);
+
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::burn_kitty(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 100));
+		assert_noop!(
+			KittiesModule::burn_kitty(Origin::signed(ALICE), kitty_id),
+			Error::<Test>::AuctionHasBids
+		);
+	});
+}
+
+#[test]
+fn force_burn_releases_auction_bid() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_ok!(KittiesModule::bid(Origin::signed(BOB), kitty_id, 100));
+		assert_noop!(
+			KittiesModule::force_burn(Origin::signed(ALICE), kitty_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(KittiesModule::force_burn(Origin::root(), kitty_id));
+
+		assert!(Kitties::<Test>::get(kitty_id).is_none());
+		assert!(Auctions::<Test>::get(kitty_id).is_none());
+		assert!(AuctionsEnding::<Test>::get(10).is_empty());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert_eq!(CountForKitties::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn approved_account_can_transfer_once() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(BOB), CHARLIE, kitty_id),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::approve(Origin::signed(ALICE), kitty_id, Some(BOB)));
+		assert_eq!(KittiesModule::approved(&kitty_id), Some(BOB));
+		System::assert_last_event(
+			Event::Approval { kitty: kitty_id, owner: ALICE, approved: Some(BOB) }.into(),
+		);
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(BOB), CHARLIE, kitty_id));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, CHARLIE);
+		// the approval was given by the previous owner
+		assert!(Approvals::<Test>::get(kitty_id).is_none());
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(BOB), ALICE, kitty_id),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn approval_can_be_revoked_and_is_cleared_on_sale() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_ok!(KittiesModule::approve(Origin::signed(ALICE), kitty_id, Some(CHARLIE)));
+		assert_ok!(KittiesModule::approve(Origin::signed(ALICE), kitty_id, None));
+		assert!(Approvals::<Test>::get(kitty_id).is_none());
+
+		assert_ok!(KittiesModule::approve(Origin::signed(ALICE), kitty_id, Some(CHARLIE)));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100));
+		assert!(Approvals::<Test>::get(kitty_id).is_none());
+	});
+}
+
+#[test]
+fn operator_can_transfer_every_kitty_of_the_owner() {
+	build_and_execute(|| {
+		let first = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_approval_for_all(Origin::signed(ALICE), BOB, true));
+		assert!(KittiesModule::is_approved_for_all(&ALICE, &BOB));
+		System::assert_last_event(
+			Event::ApprovalForAll { owner: ALICE, operator: BOB, approved: true }.into(),
+		);
+
+		// kitties received after the approval are covered too
+		let second = mint_kitty(ALICE, 1, Gender::Female);
+		assert_ok!(KittiesModule::transfer(Origin::signed(BOB), CHARLIE, first));
+		assert_ok!(KittiesModule::transfer(Origin::signed(BOB), BOB, second));
+		assert_eq!(Kitties::<Test>::get(first).unwrap().owner, CHARLIE);
+		assert_eq!(Kitties::<Test>::get(second).unwrap().owner, BOB);
+
+		let third = mint_kitty(ALICE, 2, Gender::Male);
+		assert_ok!(KittiesModule::set_approval_for_all(Origin::signed(ALICE), BOB, false));
+		assert!(OperatorApprovals::<Test>::get(ALICE, BOB).is_none());
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(BOB), CHARLIE, third),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn approve_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		assert_noop!(
+			KittiesModule::approve(Origin::signed(ALICE), [0; 16], Some(BOB)),
+			Error::<Test>::NoKitty
+		);
+
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::approve(Origin::signed(BOB), kitty_id, Some(BOB)),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::approve(Origin::signed(ALICE), kitty_id, Some(ALICE)),
+			Error::<Test>::ApproveToSelf
+		);
+		assert_noop!(
+			KittiesModule::set_approval_for_all(Origin::signed(ALICE), ALICE, true),
+			Error::<Test>::ApproveToSelf
+		);
+	});
+}
+
+#[test]
+fn nonfungible_inspect_reads_kitties() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		let other = mint_kitty(ALICE, 1, Gender::Female);
+
+		assert_eq!(<KittiesModule as Inspect<u64>>::owner(&kitty_id), Some(ALICE));
+		assert_eq!(<KittiesModule as Inspect<u64>>::owner(&[9; 16]), None);
+		assert_eq!(
+			KittiesModule::typed_attribute::<_, [u8; 16]>(&kitty_id, b"dna"),
+			Some(kitty_id)
+		);
+		assert_eq!(
+			KittiesModule::typed_attribute::<_, Gender>(&other, b"gender"),
+			Some(Gender::Female)
+		);
+		assert_eq!(KittiesModule::attribute(&kitty_id, b"price"), Some(Some(100u64).encode()));
+		assert_eq!(KittiesModule::attribute(&kitty_id, b"name"), None);
+
+		let mut owned: Vec<_> = KittiesModule::owned(&ALICE).collect();
+		owned.sort();
+		assert_eq!(owned, vec![kitty_id, other]);
+		assert_eq!(KittiesModule::items().count(), 2);
+
+		assert!(<KittiesModule as Inspect<u64>>::can_transfer(&kitty_id));
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), other, 100, 10));
+		assert!(!<KittiesModule as Inspect<u64>>::can_transfer(&other));
+	});
+}
+
+#[test]
+fn nonfungible_mutate_and_transfer_work() {
+	build_and_execute(|| {
+		let kitty_id = [3; 16];
+		assert_ok!(<KittiesModule as Mutate<u64>>::mint_into(&kitty_id, &ALICE));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().gender, Gender::Female);
+		assert_noop!(
+			<KittiesModule as Mutate<u64>>::mint_into(&kitty_id, &BOB),
+			Error::<Test>::DuplicateKitty
+		);
+
+		assert_ok!(<KittiesModule as Transfer<u64>>::transfer(&kitty_id, &BOB));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, BOB);
+
+		assert_ok!(<KittiesModule as Mutate<u64>>::burn_from(&kitty_id));
+		assert!(Kitties::<Test>::get(kitty_id).is_none());
+		assert_eq!(CountForKitties::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn ownership_index_tracks_transfers() {
+	build_and_execute(|| {
+		let first = mint_kitty(ALICE, 0, Gender::Male);
+		let second = mint_kitty(ALICE, 1, Gender::Female);
+		assert_eq!(OwnedCount::<Test>::get(ALICE), 2);
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, first));
+		assert_eq!(owned_by(ALICE), vec![second]);
+		assert_eq!(owned_by(BOB), vec![first]);
+
+		// an emptied index leaves no counter behind
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, second));
+		assert!(!OwnedCount::<Test>::contains_key(ALICE));
+		assert_eq!(OwnedCount::<Test>::get(BOB), 2);
+	});
+}
+
+#[test]
+fn migration_to_v2_rebuilds_ownership_index() {
+	build_and_execute(|| {
+		let first = mint_kitty(ALICE, 0, Gender::Male);
+		let second = mint_kitty(ALICE, 1, Gender::Female);
+		let third = mint_kitty(BOB, 2, Gender::Male);
+
+		// replace the kitties and the index with their version 1 layouts
+		for kitty_id in [first, second, third] {
+			put_v1_kitty(kitty_id);
+		}
+		let _ = OwnedKitties::<Test>::remove_all(None);
+		let _ = OwnedCount::<Test>::remove_all(None);
+		for (owner, owned) in [(ALICE, vec![first, second]), (BOB, vec![third])] {
+			frame_support::storage::migration::put_storage_value(
+				b"KittiesModule",
+				b"KittiesOwned",
+				&Twox64Concat::hash(&owner.encode()),
+				owned,
+			);
+		}
+		StorageVersion::new(1).put::<KittiesModule>();
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(owned_by(ALICE), vec![first, second]);
+		assert_eq!(owned_by(BOB), vec![third]);
+		assert!(frame_support::storage::migration::get_storage_value::<Vec<[u8; 16]>>(
+			b"KittiesModule",
+			b"KittiesOwned",
+			&Twox64Concat::hash(&ALICE.encode()),
+		)
+		.is_none());
+		assert_eq!(KittiesModule::on_chain_storage_version(), 2);
+
+		// bring the kitties to the current layout for the invariant checks
+		MigrateToV3::<Test>::on_runtime_upgrade();
+	});
+}
+
+#[test]
+fn migration_to_v3_leaves_kitties_unlocked() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_ok!(KittiesModule::set_royalty(
+			Origin::signed(ALICE),
+			kitty_id,
+			Permill::from_percent(5)
+		));
+		let before = Kitties::<Test>::get(kitty_id).unwrap();
+		put_v1_kitty(kitty_id);
+		StorageVersion::new(2).put::<KittiesModule>();
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Kitties::<Test>::get(kitty_id), Some(before));
+		assert_eq!(before.lock, None);
+		assert_eq!(KittiesModule::on_chain_storage_version(), 3);
+
+		// running it again leaves the kitties alone
+		MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(Kitties::<Test>::get(kitty_id), Some(before));
+	});
+}
+
+#[test]
+fn migration_to_v4_indexes_kitties_for_sale() {
+	build_and_execute(|| {
+		let listed = list_kitty(ALICE, 0, 100);
+		let auctioned = mint_kitty(ALICE, 1, Gender::Female);
+		assert_ok!(KittiesModule::start_dutch_auction(
+			Origin::signed(ALICE),
+			auctioned,
+			500,
+			100,
+			11
+		));
+		let unlisted = mint_kitty(BOB, 2, Gender::Male);
+		let _ = ForSale::<Test>::remove_all(None);
+		StorageVersion::new(3).put::<KittiesModule>();
+
+		MigrateToV4::<Test>::on_runtime_upgrade();
+
+		assert!(ForSale::<Test>::contains_key(listed));
+		assert!(ForSale::<Test>::contains_key(auctioned));
+		assert!(!ForSale::<Test>::contains_key(unlisted));
+		assert_eq!(KittiesModule::on_chain_storage_version(), 4);
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn genesis_config_mints_kitties() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		kitties: vec![
+			(ALICE, [0; 16], Gender::Male, None),
+			(ALICE, [1; 16], Gender::Female, Some(100)),
+			(BOB, [2; 16], Gender::Male, None),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(CountForKitties::<Test>::get(), 3);
+		assert_eq!(owned_by(ALICE), vec![[0; 16], [1; 16]]);
+		assert_eq!(owned_by(BOB), vec![[2; 16]]);
+		let kitty = Kitties::<Test>::get([1; 16]).unwrap();
+		assert_eq!(kitty.gender, Gender::Female);
+		assert_eq!(kitty.price, Some(100));
+		assert_eq!(kitty.creator, ALICE);
+		assert_ok!(KittiesModule::do_try_state());
+	});
+}
+
+#[test]
+fn genesis_funds_the_pallet_account_for_fees_below_the_existential_deposit() {
+	ExistentialDeposit::set(10);
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(BOB, INITIAL_BALANCE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	crate::GenesisConfig::<Test> { kitties: vec![(ALICE, [0; 16], Gender::Male, Some(100))] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Balances::free_balance(KittiesModule::account_id()), 10);
+
+		// the fee of 5 could not create the pallet account on its own
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), [0; 16], 100));
+		assert_eq!(Balances::free_balance(KittiesModule::account_id()), 15);
+		assert_eq!(Balances::free_balance(ALICE), 95);
+		assert_ok!(KittiesModule::do_try_state());
+	});
+}
+
+#[test]
+fn migration_funds_the_pallet_account_of_an_upgraded_chain() {
+	ExistentialDeposit::set(10);
+	build_and_execute(|| {
+		// like a chain that started before the marketplace fee, the mock has no pallet account
+		let account_id = KittiesModule::account_id();
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100),
+			pallet_balances::Error::<Test>::ExistentialDeposit
+		);
+
+		FundFeeAccount::<Test>::on_runtime_upgrade();
+		assert_eq!(Balances::free_balance(account_id), 10);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100));
+		assert_eq!(Balances::free_balance(account_id), 15);
+
+		// running it again leaves the funded account alone
+		FundFeeAccount::<Test>::on_runtime_upgrade();
+		assert_eq!(Balances::free_balance(account_id), 15);
+	});
+}
+
+#[test]
+#[should_panic(expected = "genesis kitty gender does not match its dna")]
+fn genesis_config_rejects_wrong_gender() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> { kitties: vec![(ALICE, [0; 16], Gender::Female, None)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+}
+
+#[test]
+#[should_panic(expected = "genesis kitty cannot be minted")]
+fn genesis_config_rejects_duplicate_dna() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		kitties: vec![(ALICE, [0; 16], Gender::Male, None), (BOB, [0; 16], Gender::Male, None)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+}
+
+#[test]
+fn kitty_queries_work() {
+	build_and_execute(|| {
+		let first = list_kitty(ALICE, 0, 100);
+		let second = mint_kitty(ALICE, 1, Gender::Female);
+		let third = list_kitty(BOB, 2, 300);
+		assert_ok!(KittiesModule::start_dutch_auction(Origin::signed(ALICE), second, 500, 100, 11));
+
+		assert_eq!(KittiesModule::kitty_count(), 3);
+		let mut owned = KittiesModule::kitties_of(&ALICE);
+		owned.sort();
+		assert_eq!(owned, vec![first, second]);
+		assert_eq!(KittiesModule::kitty(&third).unwrap().owner, BOB);
+
+		// every kitty is for sale, the dutch auction at its current price
+		let mut for_sale = KittiesModule::kitties_for_sale(None, 10);
+		for_sale.sort();
+		assert_eq!(for_sale, vec![(first, 100), (second, 500), (third, 300)]);
+
+		// pages pick up after the last kitty of the previous page
+		let page = KittiesModule::kitties_for_sale(None, 2);
+		assert_eq!(page.len(), 2);
+		let rest = KittiesModule::kitties_for_sale(Some(page[1].0), 2);
+		assert_eq!(rest.len(), 1);
+		assert!(!page.contains(&rest[0]));
+
+		assert_ok!(KittiesModule::set_price(Origin::signed(BOB), third, None, None));
+		assert_eq!(KittiesModule::kitties_for_sale(None, 10).len(), 2);
+		assert!(!ForSale::<Test>::contains_key(third));
+
+		// a sale takes the kitty out of the index
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(CHARLIE), first, 100));
+		assert_eq!(KittiesModule::kitties_for_sale(None, 10), vec![(second, 500)]);
+	});
+}
+
+#[test]
+fn genome_decodes_traits_from_dna() {
+	let mut dna = [0; 16];
+	assert_eq!(
+		genome::decode(&dna),
+		Traits { fur: FurColour::Black, eyes: EyeShape::Round, pattern: Pattern::Solid, rarity: 3 }
+	);
+
+	// the gender bit plays no part in the traits
+	dna[0] = 1;
+	assert_eq!(genome::decode(&dna), genome::decode(&[0; 16]));
+
+	dna[1..4].copy_from_slice(&[255, 255, 255]);
+	assert_eq!(
+		genome::decode(&dna),
+		Traits {
+			fur: FurColour::Calico,
+			eyes: EyeShape::OddEyed,
+			pattern: Pattern::Tortoiseshell,
+			rarity: 35,
+		}
+	);
+
+	// range boundaries
+	dna[1..4].copy_from_slice(&[128, 199, 224]);
+	assert_eq!(
+		genome::decode(&dna),
+		Traits {
+			fur: FurColour::Ginger,
+			eyes: EyeShape::Almond,
+			pattern: Pattern::Bicolour,
+			rarity: 10
+		}
+	);
+}
+
+#[test]
+fn try_state_detects_index_drift() {
 	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(Origin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::do_try_state());
+
+		CountForKitties::<Test>::put(2);
+		assert_eq!(
+			KittiesModule::do_try_state(),
+			Err("kittens: CountForKitties does not match the number of kitties")
+		);
+		CountForKitties::<Test>::put(1);
+
+		OwnedKitties::<Test>::remove(ALICE, kitty_id);
+		OwnedKitties::<Test>::insert(BOB, kitty_id, ());
+		assert_eq!(
+			KittiesModule::do_try_state(),
+			Err("kittens: kitty missing from its owner's index")
+		);
+		OwnedKitties::<Test>::insert(ALICE, kitty_id, ());
+		assert_eq!(
+			KittiesModule::do_try_state(),
+			Err("kittens: kitty indexed under an account that does not own it")
+		);
+		OwnedKitties::<Test>::remove(BOB, kitty_id);
+
+		OwnedCount::<Test>::insert(ALICE, 4);
+		assert_eq!(KittiesModule::do_try_state(), Err("kittens: owner holds too many kitties"));
+		OwnedCount::<Test>::insert(ALICE, 1);
+		assert_ok!(KittiesModule::do_try_state());
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn try_state_detects_priced_auction() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(TemplateModule::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 5));
+		assert_ok!(KittiesModule::do_try_state());
+
+		Kitties::<Test>::mutate(kitty_id, |kitty| kitty.as_mut().unwrap().price = Some(50));
+		assert_eq!(
+			KittiesModule::do_try_state(),
+			Err("kittens: auctioned kitty has a fixed price")
+		);
+	});
+}
+
+#[test]
+fn rent_pays_the_owner_upfront() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::offer_rental(Origin::signed(ALICE), kitty_id, 10, 5));
+		System::assert_last_event(
+			Event::RentalOffered { kitty: kitty_id, fee_per_block: 10, max_duration: 5 }.into(),
+		);
+
+		assert_ok!(KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30));
+
+		assert_eq!(KittiesModule::renter_of(&kitty_id), Some(BOB));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, ALICE);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 30);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 30);
+		assert_eq!(RentalsEnding::<Test>::get(4).into_inner(), vec![kitty_id]);
+		System::assert_last_event(
+			Event::Rented { kitty: kitty_id, owner: ALICE, renter: BOB, fee: 30, end: 4 }.into(),
+		);
+	});
+}
+
+#[test]
+fn rented_kitty_cannot_change_hands() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_ok!(KittiesModule::offer_rental(Origin::signed(ALICE), kitty_id, 10, 5));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 200, 10));
+		assert_ok!(KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30));
+
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(ALICE), CHARLIE, kitty_id),
+			Error::<Test>::Rented
+		);
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(CHARLIE), kitty_id, 100),
+			Error::<Test>::Rented
+		);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, CHARLIE),
+			Error::<Test>::Rented
+		);
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10),
+			Error::<Test>::Rented
+		);
+		assert_noop!(
+			KittiesModule::start_dutch_auction(Origin::signed(ALICE), kitty_id, 100, 50, 10),
+			Error::<Test>::Rented
+		);
+		assert_noop!(
+			KittiesModule::burn_kitty(Origin::signed(ALICE), kitty_id),
+			Error::<Test>::Rented
+		);
+		assert!(!<KittiesModule as Inspect<u64>>::can_transfer(&kitty_id));
+		// the listing stays for after the rental, but cannot be bought until then
+		assert!(ForSale::<Test>::contains_key(kitty_id));
+		assert_eq!(KittiesModule::kitties_for_sale(None, 10), vec![]);
+		assert_eq!(KittiesModule::current_price(&kitty_id), None);
+		assert_eq!(KittiesModule::kitty(&kitty_id).unwrap().price, None);
+	});
+}
+
+#[test]
+fn rental_ends_at_its_end_block() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::offer_rental(Origin::signed(ALICE), kitty_id, 10, 5));
+		assert_ok!(KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30));
+
+		System::set_block_number(4);
+		KittiesModule::on_initialize(4);
+
+		assert_eq!(KittiesModule::renter_of(&kitty_id), None);
+		assert!(RentalsEnding::<Test>::get(4).is_empty());
+		System::assert_last_event(Event::RentalEnded { kitty: kitty_id, renter: BOB }.into());
+
+		// the terms stay on offer until the kitty changes hands
+		assert_ok!(KittiesModule::rent(Origin::signed(CHARLIE), kitty_id, 5, 50));
+		System::set_block_number(9);
+		KittiesModule::on_initialize(9);
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id));
+		assert!(RentalOffers::<Test>::get(kitty_id).is_none());
+	});
+}
+
+#[test]
+fn rent_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(BOB), [9; 16], 3, 30),
+			Error::<Test>::NoKitty
+		);
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30),
+			Error::<Test>::NotForRent
+		);
+
+		assert_ok!(KittiesModule::offer_rental(Origin::signed(ALICE), kitty_id, 10, 5));
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(ALICE), kitty_id, 3, 30),
+			Error::<Test>::RentToSelf
+		);
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(BOB), kitty_id, 0, 30),
+			Error::<Test>::InvalidRentalDuration
+		);
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(BOB), kitty_id, 6, 60),
+			Error::<Test>::InvalidRentalDuration
+		);
+		// the fee for three blocks is 30
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 29),
+			Error::<Test>::BidPriceTooLow
+		);
+
+		assert_ok!(KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30));
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(CHARLIE), kitty_id, 3, 30),
+			Error::<Test>::Rented
+		);
+	});
+}
+
+#[test]
+fn rent_fails_for_auctioned_kitty_or_full_block() {
+	build_and_execute(|| {
+		let auctioned = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::offer_rental(Origin::signed(ALICE), auctioned, 10, 5));
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), auctioned, 100, 10));
+		assert_noop!(
+			KittiesModule::rent(Origin::signed(BOB), auctioned, 3, 30),
+			Error::<Test>::InAuction
+		);
+
+		// at most two rentals end in the same block
+		for seed in 1..=3 {
+			let kitty_id = mint_kitty(CHARLIE, seed, Gender::Male);
+			assert_ok!(KittiesModule::offer_rental(Origin::signed(CHARLIE), kitty_id, 10, 5));
+			if seed < 3 {
+				assert_ok!(KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30));
+			} else {
+				assert_noop!(
+					KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30),
+					Error::<Test>::TooManyRentals
+				);
+			}
+		}
+	});
+}
+
+#[test]
+fn rental_offers_are_managed_by_the_owner() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::offer_rental(Origin::signed(BOB), kitty_id, 10, 5),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::offer_rental(Origin::signed(ALICE), kitty_id, 10, 0),
+			Error::<Test>::InvalidRentalDuration
+		);
+		assert_noop!(
+			KittiesModule::withdraw_rental_offer(Origin::signed(ALICE), kitty_id),
+			Error::<Test>::NotForRent
+		);
+
+		assert_ok!(KittiesModule::offer_rental(Origin::signed(ALICE), kitty_id, 10, 5));
+		assert_noop!(
+			KittiesModule::withdraw_rental_offer(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::withdraw_rental_offer(Origin::signed(ALICE), kitty_id));
+		assert!(RentalOffers::<Test>::get(kitty_id).is_none());
+		System::assert_last_event(Event::RentalOfferWithdrawn { kitty: kitty_id }.into());
+	});
+}
+
+#[test]
+fn force_burn_ends_rental() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::offer_rental(Origin::signed(ALICE), kitty_id, 10, 5));
+		assert_ok!(KittiesModule::rent(Origin::signed(BOB), kitty_id, 3, 30));
+
+		assert_ok!(KittiesModule::force_burn(Origin::root(), kitty_id));
+
+		assert!(Rentals::<Test>::get(kitty_id).is_none());
+		assert!(RentalOffers::<Test>::get(kitty_id).is_none());
+		assert!(RentalsEnding::<Test>::get(4).is_empty());
+		// the fee is not refunded
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 30);
+	});
+}
+
+#[test]
+fn swap_exchanges_kitties_and_top_up() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		let wanted = mint_kitty(BOB, 1, Gender::Female);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), wanted, 200, 10));
+
+		assert_ok!(KittiesModule::propose_swap(Origin::signed(ALICE), kitty_id, wanted, Some(50)));
+		System::assert_last_event(
+			Event::SwapProposed { kitty: kitty_id, wanted, proposer: ALICE, top_up: Some(50) }
+				.into(),
+		);
+		// nothing moves before the swap is accepted
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+
+		assert_ok!(KittiesModule::accept_swap(Origin::signed(BOB), kitty_id));
+
+		assert_eq!(owned_by(ALICE), vec![wanted]);
+		assert_eq!(owned_by(BOB), vec![kitty_id]);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 50);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + 50);
+		// offers were made to the previous owner
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert!(Swaps::<Test>::get(kitty_id).is_none());
+		System::assert_last_event(
+			Event::Swapped {
+				kitty: kitty_id,
+				wanted,
+				proposer: ALICE,
+				acceptor: BOB,
+				top_up: Some(50),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn propose_swap_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		let own = mint_kitty(ALICE, 1, Gender::Female);
+		let wanted = mint_kitty(BOB, 2, Gender::Male);
+
+		assert_noop!(
+			KittiesModule::propose_swap(Origin::signed(BOB), kitty_id, wanted, None),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::propose_swap(Origin::signed(ALICE), kitty_id, [9; 16], None),
+			Error::<Test>::NoKitty
+		);
+		assert_noop!(
+			KittiesModule::propose_swap(Origin::signed(ALICE), kitty_id, own, None),
+			Error::<Test>::SwapWithSelf
+		);
+	});
+}
+
+#[test]
+fn accept_swap_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		let wanted = mint_kitty(BOB, 1, Gender::Female);
+		assert_noop!(
+			KittiesModule::accept_swap(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NoSwap
+		);
+
+		assert_ok!(KittiesModule::propose_swap(Origin::signed(ALICE), kitty_id, wanted, None));
+		assert_noop!(
+			KittiesModule::accept_swap(Origin::signed(CHARLIE), kitty_id),
+			Error::<Test>::NotOwner
+		);
+
+		// the wanted kitty cannot be moved while it is auctioned
+		assert_ok!(KittiesModule::start_auction(Origin::signed(BOB), wanted, 100, 10));
+		assert_noop!(
+			KittiesModule::accept_swap(Origin::signed(BOB), kitty_id),
+			Error::<Test>::InAuction
+		);
+		assert_ok!(KittiesModule::cancel_auction(Origin::signed(BOB), wanted));
+
+		// the proposal is dropped when the offered kitty changes hands
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), CHARLIE, kitty_id));
+		assert_noop!(
+			KittiesModule::accept_swap(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NoSwap
+		);
+	});
+}
+
+#[test]
+fn accept_swap_fails_cleanly_when_a_leg_fails() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		let wanted = mint_kitty(BOB, 1, Gender::Female);
+
+		// the wanted kitty is locked with bob
+		assert_ok!(KittiesModule::lock_kitty(Origin::signed(BOB), wanted, 5));
+		assert_ok!(KittiesModule::propose_swap(Origin::signed(ALICE), kitty_id, wanted, None));
+		assert_noop!(
+			KittiesModule::accept_swap(Origin::signed(BOB), kitty_id),
+			Error::<Test>::Locked
+		);
+
+		// alice cannot pay the top-up
+		System::set_block_number(5);
+		assert_ok!(KittiesModule::propose_swap(
+			Origin::signed(ALICE),
+			kitty_id,
+			wanted,
+			Some(INITIAL_BALANCE)
+		));
+		assert_noop!(
+			KittiesModule::accept_swap(Origin::signed(BOB), kitty_id),
+			pallet_balances::Error::<Test>::KeepAlive
+		);
+	});
+}
+
+#[test]
+fn accept_swap_works_when_both_owners_are_full() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		mint_kitty(ALICE, 1, Gender::Male);
+		mint_kitty(ALICE, 2, Gender::Male);
+		let wanted = mint_kitty(BOB, 3, Gender::Female);
+		mint_kitty(BOB, 4, Gender::Male);
+		mint_kitty(BOB, 5, Gender::Male);
+
+		// both already hold as many kitties as allowed, which the swap does not change
+		assert_ok!(KittiesModule::propose_swap(Origin::signed(ALICE), kitty_id, wanted, None));
+		assert_ok!(KittiesModule::accept_swap(Origin::signed(BOB), kitty_id));
+
+		assert_eq!(owned_by(ALICE), vec![[1; 16], [2; 16], wanted]);
+		assert_eq!(owned_by(BOB), vec![kitty_id, [4; 16], [5; 16]]);
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().owner, BOB);
+		assert_eq!(Kitties::<Test>::get(wanted).unwrap().owner, ALICE);
+	});
+}
+
+#[test]
+fn cancel_swap_works_only_for_the_proposer() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		let wanted = mint_kitty(BOB, 1, Gender::Female);
+		assert_noop!(
+			KittiesModule::cancel_swap(Origin::signed(ALICE), kitty_id),
+			Error::<Test>::NoSwap
+		);
+
+		assert_ok!(KittiesModule::propose_swap(Origin::signed(ALICE), kitty_id, wanted, None));
+		assert_noop!(
+			KittiesModule::cancel_swap(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::cancel_swap(Origin::signed(ALICE), kitty_id));
+		assert!(Swaps::<Test>::get(kitty_id).is_none());
+		System::assert_last_event(Event::SwapCancelled { kitty: kitty_id }.into());
+	});
+}
+
+#[test]
+fn minting_calls_pay_the_mint_fee() {
+	build_and_execute(|| {
+		MintFee::set(10);
+		let mom = mint_kitty(ALICE, 1, Gender::Female);
+		let dad = mint_kitty(ALICE, 2, Gender::Male);
+
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(BOB)));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 10);
+		assert_ok!(KittiesModule::breed_kitty(Origin::signed(ALICE), mom, dad));
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 10);
+		assert_eq!(Balances::free_balance(KittiesModule::account_id()), 20);
+
+		// a kitty that cannot be paid for is not minted
+		MintFee::set(INITIAL_BALANCE);
+		assert_noop!(
+			KittiesModule::create_kitty(Origin::signed(CHARLIE)),
+			pallet_balances::Error::<Test>::KeepAlive
+		);
+	});
+}
+
+#[test]
+fn mint_fails_above_max_supply() {
+	build_and_execute(|| {
+		MaxKittySupply::set(2);
+		mint_kitty(ALICE, 0, Gender::Male);
+		mint_kitty(BOB, 1, Gender::Female);
+
+		assert_noop!(
+			KittiesModule::create_kitty(Origin::signed(CHARLIE)),
+			Error::<Test>::MaxSupplyReached
+		);
+		assert_noop!(
+			KittiesModule::mint(&CHARLIE, [2; 16], Gender::Male),
+			Error::<Test>::MaxSupplyReached
+		);
+
+		// burning makes room again
+		assert_ok!(KittiesModule::burn_kitty(Origin::signed(ALICE), [0; 16]));
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(CHARLIE)));
+	});
+}
+
+#[test]
+fn mint_limit_is_reset_every_block() {
+	build_and_execute(|| {
+		MaxMintsPerBlock::set(1);
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(ALICE)));
+		assert_noop!(
+			KittiesModule::create_kitty(Origin::signed(BOB)),
+			Error::<Test>::MintLimitReached
+		);
+
+		System::set_block_number(2);
+		KittiesModule::on_initialize(2);
+		assert_ok!(KittiesModule::create_kitty(Origin::signed(BOB)));
+		assert_eq!(CountForKitties::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn set_kitty_name_reserves_one_deposit() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_kitty_name(Origin::signed(ALICE), kitty_id, b"tom".to_vec()));
+		assert_eq!(KittiesModule::kitty_name(&kitty_id), Some(b"tom".to_vec()));
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		System::assert_last_event(Event::NameSet { kitty: kitty_id }.into());
+
+		// renaming keeps the deposit already held
+		assert_ok!(KittiesModule::set_kitty_name(
+			Origin::signed(ALICE),
+			kitty_id,
+			b"thomas".to_vec()
+		));
+		assert_eq!(KittiesModule::kitty_name(&kitty_id), Some(b"thomas".to_vec()));
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		System::assert_last_event(Event::NameChanged { kitty: kitty_id }.into());
+	});
+}
+
+#[test]
+fn set_kitty_name_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::set_kitty_name(Origin::signed(ALICE), kitty_id, b"to".to_vec()),
+			Error::<Test>::NameTooShort
+		);
+		assert_noop!(
+			KittiesModule::set_kitty_name(Origin::signed(ALICE), kitty_id, b"tomtomtom".to_vec()),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			KittiesModule::set_kitty_name(Origin::signed(BOB), kitty_id, b"tom".to_vec()),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::set_kitty_name(Origin::signed(ALICE), [9; 16], b"tom".to_vec()),
+			Error::<Test>::NoKitty
+		);
+	});
+}
+
+#[test]
+fn clear_kitty_name_returns_the_deposit() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::clear_kitty_name(Origin::signed(ALICE), kitty_id),
+			Error::<Test>::Unnamed
+		);
+		assert_ok!(KittiesModule::set_kitty_name(Origin::signed(ALICE), kitty_id, b"tom".to_vec()));
+		assert_noop!(
+			KittiesModule::clear_kitty_name(Origin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::clear_kitty_name(Origin::signed(ALICE), kitty_id));
+		assert_eq!(KittiesModule::kitty_name(&kitty_id), None);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		System::assert_last_event(Event::NameCleared { kitty: kitty_id, deposit: 10 }.into());
+	});
+}
+
+#[test]
+fn name_deposit_follows_the_kitty() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_kitty_name(Origin::signed(ALICE), kitty_id, b"tom".to_vec()));
+
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id));
+		assert_eq!(KittiesModule::kitty_name(&kitty_id), Some(b"tom".to_vec()));
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 10);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 10);
+
+		// the new owner gets the deposit back when clearing the name
+		assert_ok!(KittiesModule::clear_kitty_name(Origin::signed(BOB), kitty_id));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + 10);
+	});
+}
+
+#[test]
+fn burn_kitty_returns_the_name_deposit() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_kitty_name(Origin::signed(ALICE), kitty_id, b"tom".to_vec()));
+
+		assert_ok!(KittiesModule::burn_kitty(Origin::signed(ALICE), kitty_id));
+		assert!(KittyNames::<Test>::get(kitty_id).is_none());
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn force_clear_name_slashes_the_deposit() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::force_clear_name(Origin::root(), kitty_id),
+			Error::<Test>::Unnamed
+		);
+		assert_ok!(KittiesModule::set_kitty_name(Origin::signed(ALICE), kitty_id, b"tom".to_vec()));
+		assert_noop!(
+			KittiesModule::force_clear_name(Origin::signed(BOB), kitty_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(KittiesModule::force_clear_name(Origin::root(), kitty_id));
+		assert_eq!(KittiesModule::kitty_name(&kitty_id), None);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 10);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		System::assert_last_event(Event::NameKilled { kitty: kitty_id, deposit: 10 }.into());
+	});
+}
+
+#[test]
+fn sales_are_recorded_in_history_and_class_stats() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_eq!(genome::decode(&kitty_id).fur, FurColour::Black);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100));
+
+		System::set_block_number(3);
+		assert_ok!(KittiesModule::set_price(Origin::signed(BOB), kitty_id, Some(200), None));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(ALICE), kitty_id, 200));
+
+		// the oldest sale makes way for the newest
+		System::set_block_number(4);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(CHARLIE), kitty_id, 300, 10));
+		assert_ok!(KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, CHARLIE));
+		assert_eq!(
+			KittiesModule::sale_history(&kitty_id),
+			vec![Sale { price: 200, block: 3 }, Sale { price: 300, block: 4 }]
+		);
+
+		let stats = KittiesModule::market_stats(FurColour::Black).unwrap();
+		assert_eq!((stats.sales, stats.last_price, stats.last_sale), (3, 300, 4));
+		assert_eq!(KittiesModule::rolling_volume(&stats), 600);
+		assert!(KittiesModule::market_stats(FurColour::White).is_none());
+
+		// the history goes with the kitty, the class statistics stay
+		assert_ok!(KittiesModule::burn_kitty(Origin::signed(CHARLIE), kitty_id));
+		assert!(!SaleHistory::<Test>::contains_key(kitty_id));
+		assert_eq!(KittiesModule::market_stats(FurColour::Black).unwrap().sales, 3);
+	});
+}
+
+#[test]
+fn rolling_volume_moves_with_the_stats_period() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 100);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 100));
+
+		// the previous period counts in full at the start of the next one, then fades out
+		System::set_block_number(11);
+		let stats = KittiesModule::market_stats(FurColour::Black).unwrap();
+		assert_eq!((stats.volume, stats.previous_volume), (0, 100));
+		assert_eq!(KittiesModule::rolling_volume(&stats), 100);
+
+		System::set_block_number(16);
+		assert_ok!(KittiesModule::set_price(Origin::signed(BOB), kitty_id, Some(40), None));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(ALICE), kitty_id, 40));
+		let stats = KittiesModule::market_stats(FurColour::Black).unwrap();
+		assert_eq!(KittiesModule::rolling_volume(&stats), 90);
+
+		// a whole period without sales leaves no volume
+		System::set_block_number(31);
+		let stats = KittiesModule::market_stats(FurColour::Black).unwrap();
+		assert_eq!(KittiesModule::rolling_volume(&stats), 0);
+		assert_eq!((stats.sales, stats.last_price, stats.last_sale), (2, 40, 16));
+	});
+}
+
+#[test]
+fn listing_expires_at_its_expiry_block() {
+	build_and_execute(|| {
+		KittiesModule::on_initialize(1);
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), Some(3)));
+		System::assert_last_event(
+			Event::PriceSet { kitty: kitty_id, price: Some(10), expiry: Some(3) }.into(),
+		);
+
+		System::set_block_number(2);
+		KittiesModule::on_initialize(2);
+		assert_eq!(KittiesModule::current_price(&kitty_id), Some(10));
+
+		// an expired listing cannot be bought even before it is withdrawn
+		System::set_block_number(3);
+		assert_eq!(KittiesModule::current_price(&kitty_id), None);
+		assert!(KittiesModule::kitties_for_sale(None, 10).is_empty());
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 10),
+			Error::<Test>::NotForSale
+		);
+
+		KittiesModule::on_initialize(3);
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().price, None);
+		assert!(!ListingExpiry::<Test>::contains_key(kitty_id));
+		assert!(!ListingsExpiring::<Test>::contains_key(3));
+		System::assert_last_event(Event::ListingExpired { kitty: kitty_id }.into());
+	});
+}
+
+#[test]
+fn expired_listings_beyond_the_limit_carry_over() {
+	build_and_execute(|| {
+		KittiesModule::on_initialize(1);
+		KittiesModule::on_initialize(2);
+		let first = mint_kitty(ALICE, 0, Gender::Male);
+		let second = mint_kitty(ALICE, 1, Gender::Male);
+		let third = mint_kitty(ALICE, 2, Gender::Male);
+		let fourth = mint_kitty(BOB, 3, Gender::Male);
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), first, Some(10), Some(3)));
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), second, Some(10), Some(4)));
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), third, Some(10), Some(4)));
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(BOB), fourth, Some(10), Some(4)),
+			Error::<Test>::TooManyListings
+		);
+
+		// block 3 was skipped, only two of the three expired listings fit in block 4
+		System::set_block_number(4);
+		KittiesModule::on_initialize(4);
+		assert_eq!(Kitties::<Test>::get(first).unwrap().price, None);
+		assert_eq!(Kitties::<Test>::get(second).unwrap().price, None);
+		assert_eq!(Kitties::<Test>::get(third).unwrap().price, Some(10));
+		assert_eq!(ListingsExpiring::<Test>::get(4).into_inner(), vec![third]);
+
+		System::set_block_number(5);
+		KittiesModule::on_initialize(5);
+		assert_eq!(Kitties::<Test>::get(third).unwrap().price, None);
+		System::assert_last_event(Event::ListingExpired { kitty: third }.into());
+	});
+}
+
+#[test]
+fn set_price_replaces_the_listing_expiry() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), Some(1)),
+			Error::<Test>::InvalidListingExpiry
+		);
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(ALICE), kitty_id, None, Some(5)),
+			Error::<Test>::InvalidListingExpiry
+		);
+
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), Some(5)));
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(20), Some(8)));
+		assert!(ListingsExpiring::<Test>::get(5).is_empty());
+		assert_eq!(ListingExpiry::<Test>::get(kitty_id), Some(8));
+
+		// a listing without expiry stays until changed, and a sale drops the expiry
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(20), None));
+		assert!(!ListingExpiry::<Test>::contains_key(kitty_id));
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(20), Some(8)));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(BOB), kitty_id, 20));
+		assert!(!ListingExpiry::<Test>::contains_key(kitty_id));
+		assert!(ListingsExpiring::<Test>::get(8).is_empty());
+	});
+}
+
+#[test]
+fn batch_transfer_moves_every_kitty() {
+	build_and_execute(|| {
+		let first = mint_kitty(ALICE, 0, Gender::Male);
+		let second = mint_kitty(ALICE, 1, Gender::Female);
+		// kitties of an owner that made the caller an operator can go in the batch as well
+		let third = mint_kitty(BOB, 2, Gender::Male);
+		assert_ok!(KittiesModule::set_approval_for_all(Origin::signed(BOB), ALICE, true));
+
+		assert_ok!(KittiesModule::batch_transfer(
+			Origin::signed(ALICE),
+			vec![(BOB, first), (CHARLIE, second), (CHARLIE, third)]
+		));
+
+		assert!(owned_by(ALICE).is_empty());
+		assert_eq!(owned_by(BOB), vec![first]);
+		assert_eq!(owned_by(CHARLIE), vec![second, third]);
+		System::assert_has_event(Event::Transferred { from: ALICE, to: BOB, kitty: first }.into());
+		System::assert_has_event(
+			Event::Transferred { from: ALICE, to: CHARLIE, kitty: second }.into(),
+		);
+		System::assert_last_event(
+			Event::Transferred { from: BOB, to: CHARLIE, kitty: third }.into(),
+		);
+	});
+}
+
+#[test]
+fn batch_transfer_is_all_or_nothing() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		let other = mint_kitty(BOB, 1, Gender::Female);
+
+		assert_noop!(
+			KittiesModule::batch_transfer(
+				Origin::signed(ALICE),
+				vec![(BOB, kitty_id), (CHARLIE, other)]
+			),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::batch_transfer(Origin::signed(ALICE), vec![(BOB, kitty_id); 4]),
+			Error::<Test>::BatchTooLarge
+		);
+	});
+}
+
+#[test]
+fn batch_mint_works() {
+	build_and_execute(|| {
+		MintFee::set(10);
+		MaxMintsPerBlock::set(1);
+		assert_noop!(
+			KittiesModule::batch_mint(Origin::signed(ALICE), vec![(ALICE, [0; 16])]),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			KittiesModule::batch_mint(Origin::root(), vec![(ALICE, [0; 16]); 4]),
+			Error::<Test>::BatchTooLarge
+		);
+		// one kitty that cannot be minted leaves every kitty of the batch unminted
+		assert_noop!(
+			KittiesModule::batch_mint(Origin::root(), vec![(ALICE, [0; 16]), (BOB, [0; 16])]),
+			Error::<Test>::DuplicateKitty
+		);
+
+		assert_ok!(KittiesModule::batch_mint(
+			Origin::root(),
+			vec![(ALICE, [0; 16]), (BOB, [1; 16])]
+		));
+
+		assert_eq!(CountForKitties::<Test>::get(), 2);
+		assert_eq!(owned_by(ALICE), vec![[0; 16]]);
+		let kitty = Kitties::<Test>::get([1; 16]).unwrap();
+		assert_eq!((kitty.owner, kitty.gender, kitty.generation), (BOB, Gender::Female, 0));
+		// airdrops are free and not held to the mint limit of the block
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		System::assert_last_event(Event::Created { kitty: [1; 16], owner: BOB }.into());
+	});
+}
+
+#[test]
+fn locked_kitty_stays_with_its_owner_until_the_lock_ends() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_ok!(KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), Some(8)));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(BOB), kitty_id, 50, 10));
+
+		// locking withdraws the listing
+		assert_ok!(KittiesModule::lock_kitty(Origin::signed(ALICE), kitty_id, 5));
+		assert_eq!(KittiesModule::lock_of(&kitty_id), Some(Lock::Until(5)));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().price, None);
+		assert!(ListingsExpiring::<Test>::get(8).is_empty());
+		System::assert_last_event(Event::Locked { kitty: kitty_id, until: 5 }.into());
+
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id),
+			Error::<Test>::Locked
+		);
+		assert_noop!(
+			KittiesModule::set_price(Origin::signed(ALICE), kitty_id, Some(10), None),
+			Error::<Test>::Locked
+		);
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(ALICE), kitty_id, BOB),
+			Error::<Test>::Locked
+		);
+		assert_noop!(
+			KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10),
+			Error::<Test>::Locked
+		);
+		assert!(!<KittiesModule as Inspect<u64>>::can_transfer(&kitty_id));
+
+		// the lock can be extended, not shortened
+		assert_noop!(
+			KittiesModule::lock_kitty(Origin::signed(ALICE), kitty_id, 4),
+			Error::<Test>::InvalidLock
+		);
+		assert_ok!(KittiesModule::lock_kitty(Origin::signed(ALICE), kitty_id, 6));
+
+		System::set_block_number(6);
+		assert_eq!(KittiesModule::lock_of(&kitty_id), None);
+		assert_ok!(KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id));
+	});
+}
+
+#[test]
+fn lock_kitty_fails_for_invalid_requests() {
+	build_and_execute(|| {
+		let kitty_id = mint_kitty(ALICE, 0, Gender::Male);
+		assert_noop!(
+			KittiesModule::lock_kitty(Origin::signed(BOB), kitty_id, 5),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::lock_kitty(Origin::signed(ALICE), kitty_id, 1),
+			Error::<Test>::InvalidLock
+		);
+		assert_ok!(KittiesModule::start_auction(Origin::signed(ALICE), kitty_id, 100, 10));
+		assert_noop!(
+			KittiesModule::lock_kitty(Origin::signed(ALICE), kitty_id, 5),
+			Error::<Test>::InAuction
+		);
+	});
+}
+
+#[test]
+fn soulbound_kitty_never_changes_hands() {
+	build_and_execute(|| {
+		let kitty_id = list_kitty(ALICE, 0, 10);
+		assert_noop!(
+			KittiesModule::soulbind(Origin::signed(ALICE), kitty_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(KittiesModule::soulbind(Origin::root(), kitty_id));
+		assert_eq!(Kitties::<Test>::get(kitty_id).unwrap().price, None);
+		System::assert_last_event(Event::Soulbound { kitty: kitty_id }.into());
+
+		System::set_block_number(1_000);
+		assert_eq!(KittiesModule::lock_of(&kitty_id), Some(Lock::Soulbound));
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(ALICE), BOB, kitty_id),
+			Error::<Test>::Locked
+		);
+		assert_noop!(
+			KittiesModule::lock_kitty(Origin::signed(ALICE), kitty_id, 2_000),
+			Error::<Test>::Locked
+		);
+		// the owner can still burn it
+		assert_ok!(KittiesModule::burn_kitty(Origin::signed(ALICE), kitty_id));
+	});
+}
+
+#[test]
+fn commit_and_reveal_mints_a_kitty() {
+	build_and_execute(|| {
+		MintFee::set(10);
+		let secret = [7; 32];
+		let hash = KittiesModule::mint_commitment(&ALICE, &secret);
+		assert_ok!(KittiesModule::commit_mint(Origin::signed(ALICE), hash));
+		System::assert_last_event(Event::MintCommitted { owner: ALICE, expires: 6 }.into());
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_noop!(
+			KittiesModule::commit_mint(Origin::signed(ALICE), hash),
+			Error::<Test>::CommitPending
+		);
+
+		// the secret can only be revealed after `RevealDelay` blocks
+		System::set_block_number(2);
+		assert_noop!(
+			KittiesModule::reveal_mint(Origin::signed(ALICE), secret),
+			Error::<Test>::RevealTooEarly
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			KittiesModule::reveal_mint(Origin::signed(ALICE), [8; 32]),
+			Error::<Test>::BadReveal
+		);
+		assert_noop!(
+			KittiesModule::reveal_mint(Origin::signed(BOB), secret),
+			Error::<Test>::NoCommit
+		);
+		assert_ok!(KittiesModule::reveal_mint(Origin::signed(ALICE), secret));
+
+		let owned = owned_by(ALICE);
+		assert_eq!(owned.len(), 1);
+		System::assert_last_event(Event::Created { kitty: owned[0], owner: ALICE }.into());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 10);
+		assert!(!MintCommits::<Test>::contains_key(ALICE));
+		assert!(CommitsExpiring::<Test>::get(6).is_empty());
+	});
+}
+
+#[test]
+fn revealed_dna_depends_on_the_secret() {
+	build_and_execute(|| {
+		for (who, secret) in [(ALICE, [1; 32]), (BOB, [2; 32])] {
+			let hash = KittiesModule::mint_commitment(&who, &secret);
+			assert_ok!(KittiesModule::commit_mint(Origin::signed(who), hash));
+		}
+
+		System::set_block_number(3);
+		assert_ok!(KittiesModule::reveal_mint(Origin::signed(ALICE), [1; 32]));
+		assert_ok!(KittiesModule::reveal_mint(Origin::signed(BOB), [2; 32]));
+		assert_ne!(owned_by(ALICE), owned_by(BOB));
+	});
+}
+
+#[test]
+fn revealed_dna_is_fixed_by_the_seed_block() {
+	// the kitty alice gets by revealing in `block`, with `seed` as the hash of block 2
+	let reveal_in = |block: u64, seed: H256| {
+		let mut dna = [0; 16];
+		build_and_execute(|| {
+			frame_system::BlockHash::<Test>::insert(2, seed);
+			let hash = KittiesModule::mint_commitment(&ALICE, &[7; 32]);
+			assert_ok!(KittiesModule::commit_mint(Origin::signed(ALICE), hash));
+			System::set_block_number(block);
+			assert_ok!(KittiesModule::reveal_mint(Origin::signed(ALICE), [7; 32]));
+			dna = owned_by(ALICE)[0];
+		});
+		dna
+	};
+
+	// revealing later does not pick another kitty, only the seed block does
+	let seed = H256::repeat_byte(1);
+	assert_eq!(reveal_in(3, seed), reveal_in(5, seed));
+	assert_ne!(reveal_in(3, seed), reveal_in(3, H256::repeat_byte(2)));
+}
+
+#[test]
+fn stale_mint_commitments_expire_with_their_deposit_forfeited() {
+	build_and_execute(|| {
+		let hash = KittiesModule::mint_commitment(&ALICE, &[7; 32]);
+		assert_ok!(KittiesModule::commit_mint(Origin::signed(ALICE), hash));
+		assert_ok!(KittiesModule::commit_mint(Origin::signed(BOB), hash));
+		// at most `MaxCommitsPerBlock` commitments expire in the same block
+		assert_noop!(
+			KittiesModule::commit_mint(Origin::signed(CHARLIE), hash),
+			Error::<Test>::TooManyCommits
+		);
+
+		System::set_block_number(6);
+		KittiesModule::on_initialize(6);
+		System::assert_has_event(Event::MintCommitExpired { owner: ALICE, deposit: 10 }.into());
+		System::assert_last_event(Event::MintCommitExpired { owner: BOB, deposit: 10 }.into());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 10);
+		assert_noop!(
+			KittiesModule::reveal_mint(Origin::signed(ALICE), [7; 32]),
+			Error::<Test>::NoCommit
+		);
+
+		// a new commitment can be made once the old one expired
+		assert_ok!(KittiesModule::commit_mint(Origin::signed(CHARLIE), hash));
 	});
 }
@@ -0,0 +1,1008 @@
+//! Weights for pallet_kittens
+//!
+//! Hand-written placeholders, not benchmark output. They are estimated from the storage
+//! accesses of every call and of the block hook, which settles every auction with 20 open
+//! offers. Replace them by running the benchmarks on reference hardware with a node built with
+//! `--features runtime-benchmarks`:
+//!
+//! ./target/release/node-template benchmark
+//! --chain=dev
+//! --execution=wasm
+//! --wasm-execution=compiled
+//! --pallet=pallet_kittens
+//! --extrinsic=*
+//! --steps=50
+//! --repeat=20
+//! --output=pallets/kittens/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_kittens.
+pub trait WeightInfo {
+	fn create_kitty(n: u32, ) -> Weight;
+	fn transfer(n: u32, o: u32, ) -> Weight;
+	fn set_price() -> Weight;
+	fn buy_kitty(n: u32, o: u32, ) -> Weight;
+	fn breed_kitty() -> Weight;
+	fn start_auction() -> Weight;
+	fn bid() -> Weight;
+	fn cancel_auction(a: u32, ) -> Weight;
+	fn start_dutch_auction() -> Weight;
+	fn make_offer(o: u32, ) -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn reject_offer() -> Weight;
+	fn accept_offer(o: u32, ) -> Weight;
+	fn set_royalty() -> Weight;
+	fn approve() -> Weight;
+	fn set_approval_for_all() -> Weight;
+	fn burn_kitty(o: u32, ) -> Weight;
+	fn force_burn(o: u32, ) -> Weight;
+	fn offer_rental() -> Weight;
+	fn withdraw_rental_offer() -> Weight;
+	fn rent() -> Weight;
+	fn propose_swap() -> Weight;
+	fn cancel_swap() -> Weight;
+	fn accept_swap(o: u32, ) -> Weight;
+	fn set_kitty_name() -> Weight;
+	fn clear_kitty_name() -> Weight;
+	fn force_clear_name() -> Weight;
+	fn batch_transfer(b: u32, ) -> Weight;
+	fn batch_mint(b: u32, ) -> Weight;
+	fn lock_kitty() -> Weight;
+	fn soulbind() -> Weight;
+	fn commit_mint() -> Weight;
+	fn reveal_mint(n: u32, ) -> Weight;
+	fn on_initialize(a: u32, r: u32, l: u32, c: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_kittens on the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties MintedInBlock (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn create_kitty(n: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Approvals (r:1 w:1)
+	// Storage: SubstrateKitties OperatorApprovals (r:1 w:0)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn transfer(n: u32, o: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:2 w:2)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn set_price() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:1 w:1)
+	// Storage: SubstrateKitties ClassStats (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn buy_kitty(n: u32, o: u32, ) -> Weight {
+		(92_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:3 w:1)
+	// Storage: SubstrateKitties BreedingCooldowns (r:2 w:2)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties MintedInBlock (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn breed_kitty() -> Weight {
+		(56_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn start_auction() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn bid() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn cancel_auction(a: u32, ) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((180_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn start_dutch_auction() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties Offers (r:1 w:2)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn make_offer(o: u32, ) -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_offer() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Offers (r:2 w:2)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:1 w:1)
+	// Storage: SubstrateKitties ClassStats (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn accept_offer(o: u32, ) -> Weight {
+		(95_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	fn set_royalty() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	fn approve() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties OperatorApprovals (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties BreedingCooldowns (r:0 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:1)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:0 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn burn_kitty(o: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties BreedingCooldowns (r:0 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: SubstrateKitties Rentals (r:1 w:1)
+	// Storage: SubstrateKitties RentalsEnding (r:1 w:1)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:0 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn force_burn(o: u32, ) -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	fn offer_rental() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:1 w:1)
+	fn withdraw_rental_offer() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:1 w:0)
+	// Storage: SubstrateKitties Rentals (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties RentalsEnding (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn rent() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:2 w:0)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	fn propose_swap() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Swaps (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Swaps (r:1 w:3)
+	// Storage: SubstrateKitties Kitties (r:3 w:2)
+	// Storage: SubstrateKitties Auctions (r:2 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:2 w:0)
+	// Storage: SubstrateKitties Rentals (r:2 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:4 w:4)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:4)
+	// Storage: SubstrateKitties Offers (r:2 w:2)
+	// Storage: SubstrateKitties OfferCount (r:0 w:2)
+	// Storage: SubstrateKitties Approvals (r:0 w:2)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: SubstrateKitties KittyNames (r:2 w:2)
+	// Storage: SubstrateKitties ListingExpiry (r:2 w:2)
+	// Storage: SubstrateKitties ListingsExpiring (r:2 w:2)
+	// Storage: SubstrateKitties ForSale (r:0 w:2)
+	fn accept_swap(o: u32, ) -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add((42_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(24 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(31 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_kitty_name() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_kitty_name() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_clear_name() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Approvals (r:1 w:1)
+	// Storage: SubstrateKitties OperatorApprovals (r:1 w:0)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn batch_transfer(b: u32, ) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((466_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads((51 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((53 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	fn batch_mint(b: u32, ) -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add((23_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn lock_kitty() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn soulbind() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: SubstrateKitties MintCommits (r:1 w:1)
+	// Storage: SubstrateKitties CommitsExpiring (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn commit_mint() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties MintCommits (r:1 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties MintedInBlock (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: SubstrateKitties CommitsExpiring (r:1 w:1)
+	fn reveal_mint(n: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: SubstrateKitties MintedInBlock (r:0 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:2 w:0)
+	// Storage: SubstrateKitties Auctions (r:2 w:1)
+	// Storage: SubstrateKitties Kitties (r:3 w:2)
+	// Storage: SubstrateKitties Rentals (r:2 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:2)
+	// Storage: SubstrateKitties Offers (r:21 w:20)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:0)
+	// Storage: System Account (r:24 w:24)
+	// Storage: SubstrateKitties SaleHistory (r:1 w:1)
+	// Storage: SubstrateKitties ClassStats (r:1 w:1)
+	// Storage: SubstrateKitties RentalsEnding (r:1 w:1)
+	// Storage: SubstrateKitties ExpiryCursor (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties CommitsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties MintCommits (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:2)
+	fn on_initialize(a: u32, r: u32, l: u32, c: u32, ) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((520_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add((8_000_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((15_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((20_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((57 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((57 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties MintedInBlock (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn create_kitty(n: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Approvals (r:1 w:1)
+	// Storage: SubstrateKitties OperatorApprovals (r:1 w:0)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn transfer(n: u32, o: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:2 w:2)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn set_price() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:1 w:1)
+	// Storage: SubstrateKitties ClassStats (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn buy_kitty(n: u32, o: u32, ) -> Weight {
+		(92_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:3 w:1)
+	// Storage: SubstrateKitties BreedingCooldowns (r:2 w:2)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties MintedInBlock (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn breed_kitty() -> Weight {
+		(56_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn start_auction() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn bid() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn cancel_auction(a: u32, ) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((180_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn start_dutch_auction() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties Offers (r:1 w:2)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn make_offer(o: u32, ) -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_offer() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Offers (r:2 w:2)
+	// Storage: SubstrateKitties OfferCount (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:1 w:1)
+	// Storage: SubstrateKitties ClassStats (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn accept_offer(o: u32, ) -> Weight {
+		(95_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(19 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	fn set_royalty() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	fn approve() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties OperatorApprovals (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties BreedingCooldowns (r:0 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:1)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:0 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn burn_kitty(o: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties BreedingCooldowns (r:0 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: SubstrateKitties Rentals (r:1 w:1)
+	// Storage: SubstrateKitties RentalsEnding (r:1 w:1)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties SaleHistory (r:0 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn force_burn(o: u32, ) -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	fn offer_rental() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:1 w:1)
+	fn withdraw_rental_offer() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:1 w:0)
+	// Storage: SubstrateKitties Rentals (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties RentalsEnding (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn rent() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:2 w:0)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	fn propose_swap() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Swaps (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SubstrateKitties Swaps (r:1 w:3)
+	// Storage: SubstrateKitties Kitties (r:3 w:2)
+	// Storage: SubstrateKitties Auctions (r:2 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:2 w:0)
+	// Storage: SubstrateKitties Rentals (r:2 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:4 w:4)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:4)
+	// Storage: SubstrateKitties Offers (r:2 w:2)
+	// Storage: SubstrateKitties OfferCount (r:0 w:2)
+	// Storage: SubstrateKitties Approvals (r:0 w:2)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: SubstrateKitties KittyNames (r:2 w:2)
+	// Storage: SubstrateKitties ListingExpiry (r:2 w:2)
+	// Storage: SubstrateKitties ListingsExpiring (r:2 w:2)
+	// Storage: SubstrateKitties ForSale (r:0 w:2)
+	fn accept_swap(o: u32, ) -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add((42_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(24 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(31 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_kitty_name() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_kitty_name() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:0)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_clear_name() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Approvals (r:1 w:1)
+	// Storage: SubstrateKitties OperatorApprovals (r:1 w:0)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties Offers (r:1 w:1)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SubstrateKitties Rentals (r:1 w:0)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:1)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn batch_transfer(b: u32, ) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((466_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads((51 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((53 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	fn batch_mint(b: u32, ) -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add((23_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn lock_kitty() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties Auctions (r:1 w:0)
+	// Storage: SubstrateKitties DutchAuctions (r:1 w:0)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:1)
+	fn soulbind() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: SubstrateKitties MintCommits (r:1 w:1)
+	// Storage: SubstrateKitties CommitsExpiring (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn commit_mint() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SubstrateKitties MintCommits (r:1 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: SubstrateKitties Kitties (r:1 w:1)
+	// Storage: SubstrateKitties CountForKitties (r:1 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:1 w:1)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:1)
+	// Storage: SubstrateKitties MintedInBlock (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: SubstrateKitties CommitsExpiring (r:1 w:1)
+	fn reveal_mint(n: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((25_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: SubstrateKitties MintedInBlock (r:0 w:1)
+	// Storage: SubstrateKitties AuctionsEnding (r:1 w:1)
+	// Storage: SubstrateKitties DutchAuctions (r:2 w:0)
+	// Storage: SubstrateKitties Auctions (r:2 w:1)
+	// Storage: SubstrateKitties Kitties (r:3 w:2)
+	// Storage: SubstrateKitties Rentals (r:2 w:1)
+	// Storage: SubstrateKitties OwnedCount (r:2 w:2)
+	// Storage: SubstrateKitties OwnedKitties (r:0 w:2)
+	// Storage: SubstrateKitties ListingExpiry (r:1 w:2)
+	// Storage: SubstrateKitties Offers (r:21 w:20)
+	// Storage: SubstrateKitties OfferCount (r:0 w:1)
+	// Storage: SubstrateKitties Approvals (r:0 w:1)
+	// Storage: SubstrateKitties RentalOffers (r:0 w:1)
+	// Storage: SubstrateKitties Swaps (r:0 w:1)
+	// Storage: SubstrateKitties KittyNames (r:1 w:0)
+	// Storage: System Account (r:24 w:24)
+	// Storage: SubstrateKitties SaleHistory (r:1 w:1)
+	// Storage: SubstrateKitties ClassStats (r:1 w:1)
+	// Storage: SubstrateKitties RentalsEnding (r:1 w:1)
+	// Storage: SubstrateKitties ExpiryCursor (r:1 w:1)
+	// Storage: SubstrateKitties ListingsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties CommitsExpiring (r:1 w:1)
+	// Storage: SubstrateKitties MintCommits (r:1 w:1)
+	// Storage: SubstrateKitties ForSale (r:0 w:2)
+	fn on_initialize(a: u32, r: u32, l: u32, c: u32, ) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((520_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add((8_000_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((15_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((20_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((57 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((57 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+}
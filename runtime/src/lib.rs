@@ -23,10 +23,14 @@ use sp_std::prelude::*;
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
+use frame_support::PalletId;
+
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU128, ConstU32, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo},
+	traits::{
+		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
@@ -104,10 +108,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 105,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 4,
 	state_version: 1,
 };
 
@@ -307,12 +311,50 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+parameter_types! {
+	// Creators can take at most a tenth of every resale.
+	pub const MaxKittyRoyalty: Permill = Permill::from_percent(10);
+	// Two and a half percent of every marketplace sale goes to the kitties pallet account.
+	pub const KittyMarketplaceFee: Permill = Permill::from_parts(25_000);
+	pub const KittiesPalletId: PalletId = PalletId(*b"py/kitty");
+	// Creating or breeding a kitty costs twice the existential deposit.
+	pub const KittyMintFee: Balance = 1_000;
+	// Held while a mint commitment waits to be revealed, forfeited if it expires unrevealed.
+	pub const KittyCommitDeposit: Balance = 1_000;
+}
+
 // implement kittens config trait for runtime
 impl pallet_kittens::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type KittyRandomness = RandomnessCollectiveFlip;
 	type MaxKittiesOwned = frame_support::pallet_prelude::ConstU32<100>;
+	type BreedingCooldown = ConstU32<{ 10 * MINUTES }>;
+	type MaxAuctionsPerBlock = ConstU32<50>;
+	type MaxAuctionDuration = ConstU32<{ 7 * DAYS }>;
+	type MaxOffersPerKitty = ConstU32<20>;
+	type MaxRoyalty = MaxKittyRoyalty;
+	type MarketplaceFee = KittyMarketplaceFee;
+	type PalletId = KittiesPalletId;
+	type MaxRentalsPerBlock = ConstU32<50>;
+	type MaxKittySupply = ConstU64<1_000_000>;
+	type MaxMintsPerBlock = ConstU32<100>;
+	type MintFee = KittyMintFee;
+	// Kitty names are paid for and bounded like nicks, but may be shorter.
+	type NameDeposit = NickReservationFee;
+	type Slashed = ();
+	type MinNameLength = ConstU32<3>;
+	type MaxNameLength = MaxNickLength;
+	type MaxSaleHistory = ConstU32<20>;
+	type StatsPeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxListingExpiries = ConstU32<50>;
+	type MaxBatchSize = ConstU32<50>;
+	// Kitties are seeded by the hash of the block after the commitment, revealed within the hour.
+	type RevealDelay = ConstU32<2>;
+	type RevealWindow = ConstU32<{ HOURS }>;
+	type CommitDeposit = KittyCommitDeposit;
+	type MaxCommitsPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_kittens::weights::SubstrateWeight<Runtime>;
 }
 
 // implement greeter config trait for runtime
@@ -388,7 +430,16 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (
+	pallet_kittens::migrations::v1::MigrateToV1<Runtime>,
+	pallet_kittens::migrations::v2::MigrateToV2<Runtime>,
+	pallet_kittens::migrations::v3::MigrateToV3<Runtime>,
+	pallet_kittens::migrations::v4::MigrateToV4<Runtime>,
+	pallet_kittens::migrations::fee_account::FundFeeAccount<Runtime>,
+);
 
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
@@ -537,6 +588,80 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_kittens_runtime_api::KittiesApi<Block, AccountId, Balance> for Runtime {
+		fn dutch_auction_price(kitty_id: [u8; 16]) -> Option<Balance> {
+			SubstrateKitties::dutch_auction_price(&kitty_id)
+		}
+
+		fn sale_fee(kitty_id: [u8; 16]) -> Option<Balance> {
+			SubstrateKitties::sale_fee(&kitty_id)
+		}
+
+		fn approved(kitty_id: [u8; 16]) -> Option<AccountId> {
+			SubstrateKitties::approved(&kitty_id)
+		}
+
+		fn is_approved_for_all(owner: AccountId, operator: AccountId) -> bool {
+			SubstrateKitties::is_approved_for_all(&owner, &operator)
+		}
+
+		fn kitties_of(owner: AccountId) -> Vec<[u8; 16]> {
+			SubstrateKitties::kitties_of(&owner)
+		}
+
+		fn kitty(
+			kitty_id: [u8; 16],
+		) -> Option<pallet_kittens_runtime_api::KittyInfo<AccountId, Balance, BlockNumber>> {
+			SubstrateKitties::kitty(&kitty_id).map(|kitty| {
+				pallet_kittens_runtime_api::KittyInfo {
+					dna: kitty.dna,
+					gender: kitty.gender,
+					traits: pallet_kittens::genome::decode(&kitty.dna),
+					price: SubstrateKitties::current_price(&kitty_id),
+					owner: kitty.owner,
+					creator: kitty.creator,
+					royalty: kitty.royalty,
+					parents: kitty.parents,
+					generation: kitty.generation,
+					lock: SubstrateKitties::lock_of(&kitty_id),
+				}
+			})
+		}
+
+		fn kitties_for_sale(start: Option<[u8; 16]>, limit: u32) -> Vec<([u8; 16], Balance)> {
+			SubstrateKitties::kitties_for_sale(start, limit)
+		}
+
+		fn kitty_count() -> u64 {
+			SubstrateKitties::kitty_count()
+		}
+
+		fn sale_history(
+			kitty_id: [u8; 16],
+		) -> Vec<pallet_kittens_runtime_api::SaleInfo<Balance, BlockNumber>> {
+			SubstrateKitties::sale_history(&kitty_id)
+				.into_iter()
+				.map(|sale| pallet_kittens_runtime_api::SaleInfo {
+					price: sale.price,
+					block: sale.block,
+				})
+				.collect()
+		}
+
+		fn market_stats(
+			fur: pallet_kittens::genome::FurColour,
+		) -> Option<pallet_kittens_runtime_api::MarketInfo<Balance, BlockNumber>> {
+			SubstrateKitties::market_stats(fur).map(|stats| {
+				pallet_kittens_runtime_api::MarketInfo {
+					volume: SubstrateKitties::rolling_volume(&stats),
+					sales: stats.sales,
+					last_price: stats.last_price,
+					last_sale: stats.last_sale,
+				}
+			})
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (